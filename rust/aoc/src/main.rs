@@ -0,0 +1,337 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use common::{AnswerStore, AocError, AocResult, DynSolution, Part, PartTimings, Stats, Verdict, load_aoc_input, timings_to_json};
+
+mod registry {
+    use common::DynSolution;
+
+    /// Every day the runner knows about, ordered by day.
+    pub fn solutions() -> Vec<Box<dyn DynSolution>> {
+        vec![
+            Box::new(day_1::Day1),
+            Box::new(day_2::Day2),
+            Box::new(day_3::Day3),
+            Box::new(day_4::Day4),
+            Box::new(day_5::Day5),
+            Box::new(day_6::Day6),
+            Box::new(day_7::Day7),
+            Box::new(day_8::Day8),
+            Box::new(day_9::Day9),
+            Box::new(day_10::Day10),
+            Box::new(day_11::Day11),
+            Box::new(day_12::Day12),
+        ]
+    }
+
+    pub fn find(day: u8) -> Option<Box<dyn DynSolution>> {
+        solutions().into_iter().find(|solution| solution.day() == day)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_days_are_unique_and_ordered() {
+            let days: Vec<u8> = solutions().iter().map(|solution| solution.day()).collect();
+            assert_eq!(days, (1..=12).collect::<Vec<u8>>());
+        }
+
+        #[test]
+        fn test_find() {
+            assert_eq!(find(5).map(|solution| solution.day()), Some(5));
+            assert!(find(25).is_none());
+        }
+    }
+}
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions of this workspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs one day, both parts unless --part is given
+    Run {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Option<Part>,
+        /// Defaults to the day's test_data/puzzle1.txt, `-` reads stdin
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Runs one day and stores its answers as the accepted ones
+    Record {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Option<Part>,
+        /// Defaults to the day's test_data/puzzle1.txt, must be inside the day's directory
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Re-runs every recorded input and flags answers which changed
+    Check {
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Times parsing and solving separately over several runs
+    Bench {
+        /// Only bench this day, otherwise every day with an input
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long)]
+        part: Option<Part>,
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Defaults to the day's test_data/puzzle1.txt
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+        /// Prints the timings as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// Lists the registered days
+    List,
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day_{day}"))
+}
+
+fn default_input(day: u8) -> String {
+    day_dir(day).join("test_data/puzzle1.txt").display().to_string()
+}
+
+fn answers_file(day: u8) -> PathBuf {
+    day_dir(day).join("answers.toml")
+}
+
+/// Recorded answers are keyed by their input path relative to the day's directory.
+fn answer_key(day: u8, input_file: &str) -> Option<String> {
+    Path::new(input_file).strip_prefix(day_dir(day)).ok()
+        .map(|relative| relative.display().to_string())
+}
+
+fn run(solution: &dyn DynSolution, parts: &[Part], input_file: &str) -> AocResult<Vec<(Part, String)>> {
+    let input = load_aoc_input(input_file)?;
+    parts.iter()
+        .map(|part| Ok((*part, solution.run(*part, &input)?)))
+        .collect()
+}
+
+fn record(solution: &dyn DynSolution, parts: &[Part], input_file: &str) -> AocResult<Vec<(Part, String)>> {
+    let day = solution.day();
+    let key = answer_key(day, input_file)
+        .ok_or_else(|| AocError::Io { path: input_file.to_string(), message: format!("recorded inputs must be inside {}", day_dir(day).display()) })?;
+    let answers = run(solution, parts, input_file)?;
+
+    let mut store = AnswerStore::load(&answers_file(day))?;
+    for (part, answer) in &answers {
+        store.record(&key, *part, answer);
+    }
+    store.save(&answers_file(day))?;
+    Ok(answers)
+}
+
+fn bench(solution: &dyn DynSolution, parts: &[Part], input_file: &str, runs: usize) -> AocResult<Vec<PartTimings>> {
+    let input = load_aoc_input(input_file)?;
+    parts.iter()
+        .map(|part| solution.bench(*part, &input, runs))
+        .collect()
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!("{:?} / {:?} / {:?}", stats.min, stats.median, stats.max)
+}
+
+/// Re-runs the recorded inputs of `solution`, printing one line per answer,
+/// and returns whether every answer still matches.
+fn check(solution: &dyn DynSolution) -> AocResult<bool> {
+    let day = solution.day();
+    let store = AnswerStore::load(&answers_file(day))?;
+    let mut all_match = true;
+
+    for key in store.inputs() {
+        let input_file = day_dir(day).join(key);
+        let input = match load_aoc_input(&input_file.display().to_string()) {
+            Ok(input) => input,
+            Err(AocError::FileNotFound(_)) => {
+                println!("Day {day} {key}: skipped, input not found");
+                continue;
+            }
+            Err(error) => return Err(error)
+        };
+
+        for part in Part::ALL.into_iter().filter(|part| store.get(key, *part).is_some()) {
+            match solution.run(part, &input) {
+                Ok(answer) => match store.check(key, part, &answer) {
+                    Verdict::Matches | Verdict::Unrecorded => println!("Day {day} part {part} {key}: ok"),
+                    Verdict::Changed { expected } => {
+                        println!("Day {day} part {part} {key}: CHANGED, expected {expected} but got {answer}");
+                        all_match = false;
+                    }
+                },
+                Err(error) => {
+                    println!("Day {day} part {part} {key}: FAILED, {error}");
+                    all_match = false;
+                }
+            }
+        }
+    }
+
+    Ok(all_match)
+}
+
+fn find_solution(day: u8) -> Result<Box<dyn DynSolution>, ExitCode> {
+    registry::find(day).ok_or_else(|| {
+        eprintln!("Day {day} is not registered");
+        ExitCode::FAILURE
+    })
+}
+
+fn print_answers(day: u8, input_file: &str, answers: AocResult<Vec<(Part, String)>>) -> ExitCode {
+    match answers {
+        Ok(answers) => {
+            for (part, answer) in answers {
+                println!("Day {day} part {part}: {answer}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Day {day} failed on {input_file}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run { day, part, input } => {
+            let solution = match find_solution(day) {
+                Ok(solution) => solution,
+                Err(code) => return code
+            };
+            let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+            let input_file = input.unwrap_or_else(|| default_input(day));
+
+            print_answers(day, &input_file, run(solution.as_ref(), &parts, &input_file))
+        }
+        Command::Record { day, part, input } => {
+            let solution = match find_solution(day) {
+                Ok(solution) => solution,
+                Err(code) => return code
+            };
+            let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+            let input_file = input.unwrap_or_else(|| default_input(day));
+
+            print_answers(day, &input_file, record(solution.as_ref(), &parts, &input_file))
+        }
+        Command::Check { day } => {
+            let solutions = match day {
+                Some(day) => match find_solution(day) {
+                    Ok(solution) => vec![solution],
+                    Err(code) => return code
+                },
+                None => registry::solutions()
+            };
+
+            let mut all_match = true;
+            for solution in solutions {
+                match check(solution.as_ref()) {
+                    Ok(matches) => all_match &= matches,
+                    Err(error) => {
+                        eprintln!("Day {} could not be checked: {error}", solution.day());
+                        all_match = false;
+                    }
+                }
+            }
+
+            if all_match { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+        }
+        Command::Bench { day, part, runs, input, json } => {
+            let solutions = match day {
+                Some(day) => match find_solution(day) {
+                    Ok(solution) => vec![solution],
+                    Err(code) => return code
+                },
+                None => registry::solutions()
+            };
+            let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+
+            let mut timings = Vec::new();
+            for solution in solutions {
+                let day = solution.day();
+                let input_file = input.clone().unwrap_or_else(|| default_input(day));
+                match bench(solution.as_ref(), &parts, &input_file, runs) {
+                    Ok(day_timings) => timings.extend(day_timings),
+                    Err(AocError::FileNotFound(_)) if input.is_none() => eprintln!("Day {day}: skipped, input not found"),
+                    Err(error) => {
+                        eprintln!("Day {day} failed on {input_file}: {error}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            if json {
+                println!("{}", timings_to_json(&timings));
+            } else {
+                println!("min / median / max over {runs} runs");
+                for timing in &timings {
+                    println!("Day {} part {}: {}", timing.day, timing.part, timing.answer);
+                    println!("    parse {}", format_stats(&timing.parse));
+                    println!("    solve {}", format_stats(&timing.solve));
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::List => {
+            for solution in registry::solutions() {
+                println!("Day {}", solution.day());
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let solution = registry::find(2).unwrap();
+        assert_eq!(run(solution.as_ref(), &Part::ALL, "../day_2/test_data/e1.txt"),
+                   Ok(vec![(Part::One, "8".to_string()), (Part::Two, "2286".to_string())]));
+        assert_eq!(run(solution.as_ref(), &[Part::Two], "../day_2/test_data/e1.txt"),
+                   Ok(vec![(Part::Two, "2286".to_string())]));
+    }
+
+    #[test]
+    fn test_bench() {
+        let solution = registry::find(2).unwrap();
+        let timings = bench(solution.as_ref(), &[Part::Two], "../day_2/test_data/e1.txt", 3).unwrap();
+        assert_eq!(timings.len(), 1);
+        assert_eq!((timings[0].part, timings[0].runs, timings[0].answer.as_str()), (Part::Two, 3, "2286"));
+    }
+
+    #[test]
+    fn test_answer_key() {
+        assert_eq!(answer_key(5, "day_5/test_data/puzzle1.txt"), Some("test_data/puzzle1.txt".to_string()));
+        assert_eq!(answer_key(5, "day_6/test_data/puzzle1.txt"), None);
+    }
+
+    #[test]
+    fn test_run_reports_missing_input() {
+        let solution = registry::find(2).unwrap();
+        assert!(run(solution.as_ref(), &Part::ALL, "missing.txt").is_err());
+    }
+}
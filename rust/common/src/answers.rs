@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AocError, AocResult};
+use crate::solution::Part;
+
+/// Accepted answers of one day, keyed by input file. Stored as TOML:
+///
+/// ```toml
+/// [inputs."test_data/puzzle1.txt"]
+/// part1 = "35"
+/// part2 = "46"
+/// ```
+#[derive(PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct AnswerStore {
+    #[serde(default)]
+    inputs: BTreeMap<String, RecordedAnswers>,
+}
+
+#[derive(PartialEq, Debug, Default, Serialize, Deserialize)]
+struct RecordedAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl RecordedAnswers {
+    fn get_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Verdict {
+    Matches,
+    Changed { expected: String },
+    Unrecorded,
+}
+
+impl AnswerStore {
+    /// Reads the store at `path`, which is empty if the file does not exist yet.
+    pub fn load(path: &Path) -> AocResult<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(io_error(path, error.to_string()))
+        };
+        toml::from_str(&content).map_err(|error| io_error(path, error.to_string()))
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        let content = toml::to_string(self).map_err(|error| io_error(path, error.to_string()))?;
+        fs::write(path, content).map_err(|error| io_error(path, error.to_string()))
+    }
+
+    /// Input files with at least one recorded answer.
+    pub fn inputs(&self) -> impl Iterator<Item=&str> {
+        self.inputs.keys().map(|input| input.as_str())
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        let answers = self.inputs.get(input)?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn record(&mut self, input: &str, part: Part, answer: &str) {
+        *self.inputs.entry(input.to_string()).or_default().get_mut(part) = Some(answer.to_string());
+    }
+
+    pub fn check(&self, input: &str, part: Part, answer: &str) -> Verdict {
+        match self.get(input, part) {
+            Some(expected) if expected == answer => Verdict::Matches,
+            Some(expected) => Verdict::Changed { expected: expected.to_string() },
+            None => Verdict::Unrecorded,
+        }
+    }
+}
+
+fn io_error(path: &Path, message: String) -> AocError {
+    AocError::Io { path: path.display().to_string(), message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_check() {
+        let mut store = AnswerStore::default();
+        store.record("test_data/e1.txt", Part::One, "35");
+
+        assert_eq!(store.check("test_data/e1.txt", Part::One, "35"), Verdict::Matches);
+        assert_eq!(store.check("test_data/e1.txt", Part::One, "36"), Verdict::Changed { expected: "35".to_string() });
+        assert_eq!(store.check("test_data/e1.txt", Part::Two, "46"), Verdict::Unrecorded);
+        assert_eq!(store.check("test_data/e2.txt", Part::One, "35"), Verdict::Unrecorded);
+        assert_eq!(store.inputs().collect::<Vec<&str>>(), vec!["test_data/e1.txt"]);
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let content = "[inputs.\"test_data/e1.txt\"]\npart1 = \"35\"\npart2 = \"46\"\n";
+        let store: AnswerStore = toml::from_str(content).unwrap();
+
+        assert_eq!(store.get("test_data/e1.txt", Part::Two), Some("46"));
+        assert_eq!(toml::to_string(&store).unwrap(), content);
+    }
+
+    #[test]
+    fn test_load_missing_store_is_empty() {
+        assert_eq!(AnswerStore::load(Path::new("test_data/missing.toml")), Ok(AnswerStore::default()));
+    }
+}
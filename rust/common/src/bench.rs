@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::solution::Part;
+
+/// Spread of the timings of one phase over several runs.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Serialize)]
+pub struct Stats {
+    #[serde(serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Sorts `samples` in place; the median of an even count is the upper middle sample.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+        Stats {
+            min: samples.first().copied().unwrap_or_default(),
+            median: samples.get(samples.len() / 2).copied().unwrap_or_default(),
+            max: samples.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Timings of one part of one day, with durations reported in nanoseconds
+/// when serialized.
+#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+pub struct PartTimings {
+    pub day: u8,
+    #[serde(serialize_with = "as_number")]
+    pub part: Part,
+    pub runs: usize,
+    pub answer: String,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Renders `timings` as a JSON array, for comparing runs with other tools.
+pub fn timings_to_json(timings: &[PartTimings]) -> String {
+    serde_json::to_string_pretty(timings).expect("timings are always serializable")
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+}
+
+fn as_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(match part {
+        Part::One => 1,
+        Part::Two => 2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [5, 1, 3, 2].map(Duration::from_millis);
+        assert_eq!(Stats::from_samples(&mut samples), Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(5),
+        });
+        assert_eq!(Stats::from_samples(&mut []).max, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_timings_to_json() {
+        let stats = Stats { min: Duration::from_nanos(1), median: Duration::from_nanos(2), max: Duration::from_nanos(3) };
+        let timings = PartTimings { day: 5, part: Part::Two, runs: 3, answer: "46".to_string(), parse: stats, solve: stats };
+
+        let json: serde_json::Value = serde_json::from_str(&timings_to_json(&[timings])).unwrap();
+        assert_eq!(json[0]["day"], 5);
+        assert_eq!(json[0]["part"], 2);
+        assert_eq!(json[0]["solve"]["median"], 2);
+    }
+}
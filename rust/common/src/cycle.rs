@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of a sequence of states which eventually repeats, with the indices
+/// at which its goal states occur.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Cycle {
+    /// Index of the first state which is part of the cycle.
+    pub start: usize,
+    pub length: usize,
+    /// Indices of the goal states seen before the cycle starts.
+    pub lead_in_goals: Vec<usize>,
+    /// Offsets from `start` of the goal states within one round of the cycle.
+    pub goal_offsets: Vec<usize>,
+}
+
+impl Cycle {
+    pub fn is_goal(&self, idx: usize) -> bool {
+        match idx.checked_sub(self.start) {
+            Some(offset) => self.goal_offsets.contains(&(offset % self.length)),
+            None => self.lead_in_goals.contains(&idx),
+        }
+    }
+
+    /// Every index holding a goal state, in increasing order. Endless unless
+    /// the cycle holds no goal.
+    pub fn goal_indices(&self) -> impl Iterator<Item=usize> + '_ {
+        let repeating = !self.goal_offsets.is_empty();
+        self.lead_in_goals.iter().copied()
+            .chain((0..).take_while(move |_| repeating)
+                .flat_map(move |round| self.goal_offsets.iter().map(move |offset| self.start + round * self.length + offset)))
+    }
+}
+
+/// Follows `states` until one repeats, remembering every state seen. Returns
+/// `None` when `states` ends first.
+pub fn find_cycle<S, I, G>(states: I, mut is_goal: G) -> Option<Cycle>
+    where S: Eq + Hash,
+          I: IntoIterator<Item=S>,
+          G: FnMut(&S) -> bool
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut goals = Vec::new();
+
+    for (idx, state) in states.into_iter().enumerate() {
+        if let Some(&start) = seen.get(&state) {
+            let (lead_in_goals, cycle_goals): (Vec<usize>, Vec<usize>) = goals.into_iter().partition(|goal| *goal < start);
+            return Some(Cycle {
+                start,
+                length: idx - start,
+                lead_in_goals,
+                goal_offsets: cycle_goals.into_iter().map(|goal| goal - start).collect(),
+            });
+        }
+        if is_goal(&state) {
+            goals.push(idx);
+        }
+        seen.insert(state, idx);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3, ...
+    fn states() -> impl Iterator<Item=u32> {
+        (0..).map(|idx| if idx < 3 { idx } else { 3 + (idx - 3) % 4 })
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(find_cycle(states(), |state| state % 2 == 0), Some(Cycle {
+            start: 3,
+            length: 4,
+            lead_in_goals: vec![0, 2],
+            goal_offsets: vec![1, 3],
+        }));
+        assert_eq!(find_cycle(0..10, |_| true), None);
+    }
+
+    #[test]
+    fn test_goals() {
+        let cycle = find_cycle(states(), |state| state % 2 == 0).unwrap();
+        assert_eq!(cycle.goal_indices().take(6).collect::<Vec<_>>(), vec![0, 2, 4, 6, 8, 10]);
+        assert!(cycle.is_goal(2) && cycle.is_goal(40) && !cycle.is_goal(41) && !cycle.is_goal(1));
+
+        let cycle = find_cycle(states(), |state| *state == 1).unwrap();
+        assert_eq!(cycle.goal_indices().collect::<Vec<_>>(), vec![1]);
+    }
+}
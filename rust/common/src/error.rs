@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter};
+
+/// Everything that can go wrong while reading, parsing and solving a puzzle input.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum AocError {
+    FileNotFound(String),
+    Io { path: String, message: String },
+    /// `line` and `column` are both 1-based.
+    BadLine { line: usize, column: usize, message: String },
+    UnexpectedEnd(String),
+    /// The input parsed, but it has no answer, e.g. a start node is missing.
+    NoSolution(String),
+    /// An intermediate value grew past what its integer type holds.
+    Overflow(String),
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    /// An error in a single line of input, reported as line 1 until the caller
+    /// moves it with [`AocError::offset_lines`].
+    pub fn bad_line(column: usize, message: impl Into<String>) -> Self {
+        AocError::BadLine { line: 1, column, message: message.into() }
+    }
+
+    /// A line which stopped before `expected` could be read.
+    pub fn missing(line: &str, expected: &str) -> Self {
+        Self::bad_line(line.chars().count() + 1, format!("expected {expected}"))
+    }
+
+    /// Moves the reported line down by `offset`, for errors raised by a parser
+    /// which only saw a slice of the input.
+    pub fn offset_lines(self, offset: usize) -> Self {
+        match self {
+            AocError::BadLine { line, column, message } => AocError::BadLine { line: line + offset, column, message },
+            other => other
+        }
+    }
+
+    /// Moves the reported column right by `offset`, for errors raised by a
+    /// parser which only saw part of a line.
+    pub fn offset_columns(self, offset: usize) -> Self {
+        match self {
+            AocError::BadLine { line, column, message } => AocError::BadLine { line, column: column + offset, message },
+            other => other
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::FileNotFound(path) => write!(f, "input file not found: {path}"),
+            AocError::Io { path, message } => write!(f, "could not read {path}: {message}"),
+            AocError::BadLine { line, column, message } => write!(f, "line {line}, column {column}: {message}"),
+            AocError::UnexpectedEnd(expected) => write!(f, "unexpected end of input, expected {expected}"),
+            AocError::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            AocError::Overflow(what) => write!(f, "overflow: {what}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(AocError::bad_line(3, "bad").offset_lines(4),
+                   AocError::BadLine { line: 5, column: 3, message: "bad".to_string() });
+        assert_eq!(AocError::UnexpectedEnd("seeds".to_string()).offset_lines(4),
+                   AocError::UnexpectedEnd("seeds".to_string()));
+        assert_eq!(AocError::bad_line(3, "bad").offset_columns(4),
+                   AocError::BadLine { line: 1, column: 7, message: "bad".to_string() });
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(AocError::bad_line(7, "expected number").offset_lines(2).to_string(),
+                   "line 3, column 7: expected number");
+        assert_eq!(AocError::missing("Card 1", "':'"),
+                   AocError::BadLine { line: 1, column: 7, message: "expected ':'".to_string() });
+    }
+}
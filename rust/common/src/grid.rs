@@ -0,0 +1,208 @@
+use crate::error::{AocError, AocResult};
+
+/// Offsets of the orthogonal neighbours, clockwise from north.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise from north.
+const ALL_AROUND: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A rectangular grid of cells addressed by `(x, y)`, with `y` growing downwards.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows of equal length, panicking otherwise. Inputs should
+    /// go through [`Grid::parse`] instead.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "rows of a grid must have the same length");
+        Self { width, height: rows.len(), cells: rows.into_iter().flatten().collect() }
+    }
+
+    /// Reads one cell per char with `cell`, reporting the chars it rejects and
+    /// rows narrower or wider than the first one.
+    pub fn parse<F>(lines: &[String], cell: F) -> AocResult<Self>
+        where F: Fn(char) -> Option<T>
+    {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| AocError::bad_line(x + 1, format!("invalid char '{c}'")).offset_lines(y))?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - row_start;
+            if row_width != width {
+                return Err(AocError::bad_line(row_width.min(width) + 1, format!("expected {width} cells, found {row_width}")).offset_lines(y));
+            }
+        }
+        Ok(Self { width, height: lines.len(), cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        let idx = self.index(position)?;
+        self.cells.get(idx)
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        let idx = self.index(position)?;
+        self.cells.get_mut(idx)
+    }
+
+    /// The position `(dx, dy)` away from `position`, if it is still on the grid.
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The orthogonal neighbours of `position` which are on the grid.
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        ORTHOGONAL.into_iter().filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of `position` which are on the grid.
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        ALL_AROUND.into_iter().filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        (0..self.height).filter_map(move |y| self.get((x, y)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+        where F: Fn(&T) -> U
+    {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+        where T: Clone
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Draws the grid with one char per cell, rows separated by `\n`.
+    pub fn render<F>(&self, cell: F) -> String
+        where F: Fn(&T) -> char
+    {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn index(&self, position: (usize, usize)) -> Option<usize> {
+        self.contains(position).then(|| position.1 * self.width + position.0)
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(lines: &[String]) -> AocResult<Self> {
+        Self::parse(lines, Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::aoc_input_from_str;
+
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse_chars(&aoc_input_from_str("abc\ndef")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(example(), Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]));
+        assert_eq!(Grid::parse(&aoc_input_from_str("01\n1x"), |c| c.to_digit(2)),
+                   Err(AocError::BadLine { line: 2, column: 2, message: "invalid char 'x'".to_string() }));
+        assert_eq!(Grid::parse_chars(&aoc_input_from_str("abc\nd")),
+                   Err(AocError::BadLine { line: 2, column: 2, message: "expected 3 cells, found 1".to_string() }));
+        assert_eq!(Grid::parse_chars(&aoc_input_from_str("abc\ndefg")),
+                   Err(AocError::BadLine { line: 2, column: 4, message: "expected 3 cells, found 4".to_string() }));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        *grid.get_mut((0, 0)).unwrap() = 'z';
+        assert_eq!(grid.get((0, 0)), Some(&'z'));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).collect::<Vec<_>>(), vec![(1, 0), (2, 1), (0, 1)]);
+        assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (1, 1), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.offset((2, 1), (1, 0)), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().find(|(_, c)| **c == 'e').map(|(position, _)| position), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_transpose_and_render() {
+        let grid = example();
+        assert_eq!(grid.transpose().render(|c| *c), "ad\nbe\ncf");
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(grid.map(|c| c.is_ascii_lowercase()).render(|lower| if *lower { '#' } else { '.' }), "###\n###");
+    }
+}
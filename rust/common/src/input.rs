@@ -0,0 +1,208 @@
+use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, ErrorKind, Read};
+use std::str::FromStr;
+
+use crate::error::{AocError, AocResult};
+
+/// Input name which makes [`load_aoc_input`] read stdin.
+pub const STDIN: &str = "-";
+
+/// Whether the loaders strip the whitespace around each line.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Whitespace {
+    Trim,
+    Keep,
+}
+
+/// Reads the trimmed lines of `file_name`, or of stdin when it is [`STDIN`].
+pub fn load_aoc_input(file_name: &str) -> AocResult<Vec<String>> {
+    load_aoc_input_with(file_name, Whitespace::Trim)
+}
+
+pub fn load_aoc_input_with(file_name: &str, whitespace: Whitespace) -> AocResult<Vec<String>> {
+    if file_name == STDIN {
+        return read_named(io::stdin().lock(), "stdin", whitespace);
+    }
+    let file = File::open(file_name).map_err(|error| io_error(file_name, error))?;
+    read_named(file, file_name, whitespace)
+}
+
+/// Reads the trimmed lines of any reader, e.g. a `BufReader` or a byte slice.
+pub fn read_aoc_input(reader: impl Read) -> AocResult<Vec<String>> {
+    read_named(reader, "input", Whitespace::Trim)
+}
+
+/// Splits an in-memory input, e.g. an example embedded in a test, into trimmed lines.
+pub fn aoc_input_from_str(content: &str) -> Vec<String> {
+    split_lines(content, Whitespace::Trim)
+}
+
+/// Splits `content` at `\n` or `\r\n`. A final line terminator does not start
+/// another, empty, line.
+pub fn split_lines(content: &str, whitespace: Whitespace) -> Vec<String> {
+    content.lines()
+        .map(|line| match whitespace {
+            Whitespace::Trim => line.trim().to_string(),
+            Whitespace::Keep => line.to_string(),
+        })
+        .collect()
+}
+
+/// A run of non-blank lines, with the index of its first line in the whole input.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Block<'a> {
+    pub offset: usize,
+    pub lines: &'a [String],
+}
+
+impl Block<'_> {
+    /// Parses the block with `parse`, reporting errors at their line in the whole input.
+    pub fn parse<T, F>(&self, parse: F) -> AocResult<T>
+        where F: FnOnce(&[String]) -> AocResult<T>
+    {
+        parse(self.lines).map_err(|error| error.offset_lines(self.offset))
+    }
+}
+
+/// Splits `lines` into blocks separated by one or more blank lines.
+pub fn blocks(lines: &[String]) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    for (idx, line) in lines.iter().enumerate().chain([(lines.len(), &String::new())]) {
+        if line.trim().is_empty() {
+            if start < idx {
+                blocks.push(Block { offset: start, lines: &lines[start..idx] });
+            }
+            start = idx + 1;
+        }
+    }
+    blocks
+}
+
+/// Parses every block with `parse`, reporting errors at their line in `lines`.
+pub fn parse_blocks<T, F>(lines: &[String], parse: F) -> AocResult<Vec<T>>
+    where F: Fn(&[String]) -> AocResult<T>
+{
+    blocks(lines).iter()
+        .map(|block| block.parse(&parse))
+        .collect()
+}
+
+/// The input named by the first command line argument, `default` when there is none.
+pub fn input_path_from_args(default: &str) -> String {
+    env::args().nth(1).unwrap_or_else(|| default.to_string())
+}
+
+fn read_named(mut reader: impl Read, name: &str, whitespace: Whitespace) -> AocResult<Vec<String>> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content).map_err(|error| io_error(name, error))?;
+    Ok(split_lines(&content, whitespace))
+}
+
+fn io_error(name: &str, error: io::Error) -> AocError {
+    match error.kind() {
+        ErrorKind::NotFound => AocError::FileNotFound(name.to_string()),
+        _ => AocError::Io { path: name.to_string(), message: error.to_string() }
+    }
+}
+
+/// Parses every line with `parse`, reporting errors at their line in `lines`.
+pub fn parse_lines<T, F>(lines: &[String], parse: F) -> AocResult<Vec<T>>
+    where F: Fn(&str) -> AocResult<T>
+{
+    lines.iter()
+        .enumerate()
+        .map(|(idx, line)| parse(line).map_err(|error| error.offset_lines(idx)))
+        .collect()
+}
+
+/// 1-based column of `token`, which must be a slice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line.get(..offset).map_or(0, |prefix| prefix.chars().count()) + 1
+}
+
+/// Parses `token`, a slice of `line`, reporting failures at the token's column.
+pub fn parse_token<T>(line: &str, token: &str) -> AocResult<T>
+    where T: FromStr, T::Err: Display
+{
+    token.parse()
+        .map_err(|error| AocError::bad_line(column_of(line, token), format!("invalid value '{token}': {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_has_4_lines() {
+        let data = load_aoc_input("test_data/test.txt").unwrap();
+        assert_eq!(data.len(),4);
+
+    }
+
+    #[test]
+    fn test_missing_file() {
+        assert_eq!(load_aoc_input("test_data/missing.txt"),
+                   Err(AocError::FileNotFound("test_data/missing.txt".to_string())));
+    }
+
+    #[test]
+    fn test_read_aoc_input() {
+        assert_eq!(read_aoc_input("a\nb \n c".as_bytes()), Ok(vec!["a".to_string(), "b".to_string(), "c".to_string()]));
+        assert_eq!(read_aoc_input(std::io::BufReader::new(&[0xffu8, b'\n'][..])),
+                   Err(AocError::Io { path: "input".to_string(), message: "stream did not contain valid UTF-8".to_string() }));
+    }
+
+    #[test]
+    fn test_aoc_input_from_str() {
+        assert_eq!(aoc_input_from_str("a\nb"), load_aoc_input("test_data/test.txt").unwrap()[..2]);
+    }
+
+    #[test]
+    fn test_split_lines() {
+        assert_eq!(split_lines("a\n b\r\n\nc\n", Whitespace::Trim), ["a", "b", "", "c"]);
+        assert_eq!(split_lines(" a\n b \n", Whitespace::Keep), [" a", " b "]);
+        assert!(split_lines("", Whitespace::Trim).is_empty());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let lines = aoc_input_from_str("a\nb\n\n\nc\n\n");
+        assert_eq!(blocks(&lines), vec![
+            Block { offset: 0, lines: &lines[0..2] },
+            Block { offset: 4, lines: &lines[4..5] },
+        ]);
+        assert!(blocks(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let lines = aoc_input_from_str("1\n2\n\n3\nx");
+        let parse = |block: &[String]| parse_lines(block, |line| parse_token::<u32>(line, line));
+
+        assert_eq!(parse_blocks(&lines[..3], parse), Ok(vec![vec![1, 2]]));
+        assert_eq!(parse_blocks(&lines, parse),
+                   Err(AocError::BadLine { line: 5, column: 1, message: "invalid value 'x': invalid digit found in string".to_string() }));
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let line = "Game 12: 3 blue";
+        assert_eq!(parse_token::<u32>(line, &line[5..7]), Ok(12));
+        assert_eq!(parse_token::<u32>(line, &line[9..15]),
+                   Err(AocError::bad_line(10, "invalid value '3 blue': invalid digit found in string")));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let lines: Vec<String> = vec!["1".to_string(), "2".to_string(), "x".to_string()];
+        let parse = |line: &str| parse_token::<u32>(line, line);
+
+        assert_eq!(parse_lines(&lines[..2], parse), Ok(vec![1, 2]));
+        assert_eq!(parse_lines(&lines, parse),
+                   Err(AocError::BadLine { line: 3, column: 1, message: "invalid value 'x': invalid digit found in string".to_string() }));
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::Range;
+
+/// A set of values stored as sorted, disjoint and non-touching half-open ranges.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Sorts `ranges`, drops the empty ones and merges those which overlap or touch.
+    pub fn normalise(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges.get(idx).is_some_and(|range| range.contains(value))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&IntervalSet::from(range));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalise(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (self.ranges.iter().peekable(), other.ranges.iter().peekable());
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end <= b.end { left.next(); } else { right.next(); }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values of `self` which are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = others.peek() {
+                if cut.end <= start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if cut.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl IntervalSet<usize> {
+    /// Number of values in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|range| range.len()).sum()
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalise(vec![range])
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=Range<T>>>(ranges: I) -> Self {
+        Self::normalise(ranges.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(IntervalSet::normalise(vec![5..8, 1..3, 3..4, 7..10, 12..12]).ranges(), &[1..4, 5..10]);
+        let (start, end) = (4usize, 2);
+        assert!(IntervalSet::from(start..end).is_empty());
+    }
+
+    #[test]
+    fn test_operations() {
+        let a: IntervalSet<usize> = [0..10, 20..30].into_iter().collect();
+        let b = IntervalSet::from(5..25);
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..30]);
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert_eq!((a.len(), a.min()), (20, Some(0)));
+        assert!(a.contains(&9) && !a.contains(&10) && a.contains(&20));
+    }
+
+    fn values(set: &IntervalSet<usize>) -> BTreeSet<usize> {
+        set.ranges().iter().flat_map(|range| range.clone()).collect()
+    }
+
+    fn interval_set() -> impl Strategy<Value=IntervalSet<usize>> {
+        vec((0..60usize, 0..15usize).prop_map(|(start, length)| start..start + length), 0..6)
+            .prop_map(IntervalSet::normalise)
+    }
+
+    proptest! {
+        #[test]
+        fn normalised_ranges_are_sorted_and_apart(set in interval_set()) {
+            prop_assert!(set.ranges().iter().all(|range| range.start < range.end));
+            prop_assert!(set.ranges().windows(2).all(|pair| pair[0].end < pair[1].start));
+        }
+
+        #[test]
+        fn operations_match_sets(a in interval_set(), b in interval_set()) {
+            let (va, vb) = (values(&a), values(&b));
+
+            prop_assert_eq!(values(&a.union(&b)), va.union(&vb).copied().collect::<BTreeSet<_>>());
+            prop_assert_eq!(values(&a.intersection(&b)), va.intersection(&vb).copied().collect::<BTreeSet<_>>());
+            prop_assert_eq!(values(&a.difference(&b)), va.difference(&vb).copied().collect::<BTreeSet<_>>());
+            prop_assert_eq!(a.len(), va.len());
+            prop_assert!((0..80).all(|value| a.contains(&value) == va.contains(&value)));
+        }
+    }
+}
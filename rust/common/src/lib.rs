@@ -1,19 +1,24 @@
-use std::fs;
+mod answers;
+mod bench;
+mod cycle;
+mod error;
+mod grid;
+mod input;
+mod intervals;
+mod math;
+mod search;
+mod solution;
 
-pub fn load_aoc_input(file_name: &str) -> Vec<String> {
-    let content = fs::read_to_string(file_name).unwrap();
-    content.split('\n').map(|s| {String::from(s.trim())}).collect()
-
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_data_has_4_lines() {
-        let data = load_aoc_input("test_data/test.txt");
-        assert_eq!(data.len(),4);
-
-    }
-}
+pub use answers::{AnswerStore, Verdict};
+pub use bench::{PartTimings, Stats, time, timings_to_json};
+pub use cycle::{Cycle, find_cycle};
+pub use error::{AocError, AocResult};
+pub use grid::Grid;
+pub use input::{
+    Block, STDIN, Whitespace, aoc_input_from_str, blocks, column_of, input_path_from_args, load_aoc_input,
+    load_aoc_input_with, parse_blocks, parse_lines, parse_token, read_aoc_input, split_lines,
+};
+pub use intervals::IntervalSet;
+pub use math::{crt, gcd, gcd_of, lcm, lcm_of};
+pub use search::{Path, astar, bfs, dijkstra, reachable};
+pub use solution::{DynSolution, Part, Solution, print_answers};
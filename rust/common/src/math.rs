@@ -0,0 +1,121 @@
+use crate::{AocError, AocResult};
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// `None` when the result does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 { Some(0) } else { (a / gcd(a, b)).checked_mul(b) }
+}
+
+/// Greatest common divisor of all `numbers`, 0 when there are none.
+pub fn gcd_of(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, number| gcd(acc, *number))
+}
+
+/// Least common multiple of all `numbers`, 1 when there are none and `None`
+/// when it does not fit in a `u64`.
+pub fn lcm_of(numbers: &[u64]) -> Option<u64> {
+    numbers.iter().try_fold(1, |acc, number| lcm(acc, *number))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, the
+/// moduli need not be coprime. Returns the smallest non-negative `x` with the
+/// lcm of the moduli, after which the solutions repeat, or `None` when the
+/// congruences contradict each other or a modulus is 0. Fails with
+/// [`AocError::Overflow`] when the lcm does not fit in a `u64`.
+pub fn crt(congruences: &[(u64, u64)]) -> AocResult<Option<(u64, u64)>> {
+    let mut solution = (0, 1);
+    for &(residue, modulus) in congruences {
+        if modulus == 0 {
+            return Ok(None);
+        }
+        match combine(solution, (i128::from(residue % modulus), i128::from(modulus)))? {
+            Some(combined) => solution = combined,
+            None => return Ok(None),
+        }
+    }
+    // `combine` keeps the period within a u64, and `x` below the period.
+    let (x, period) = solution;
+    Ok(Some((x as u64, period as u64)))
+}
+
+fn combine((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> AocResult<Option<(i128, i128)>> {
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return Ok(None);
+    }
+    let step = m2 / g;
+    let period = m1.checked_mul(step)
+        .filter(|period| *period <= i128::from(u64::MAX))
+        .ok_or_else(|| AocError::Overflow("the lcm of the moduli does not fit in a u64".to_string()))?;
+    // Both factors are below `step`, which fits in a u64, so the product fits in a u128.
+    let k = ((r2 - r1) / g).rem_euclid(step) as u128 * p.rem_euclid(step) as u128 % step as u128;
+    Ok(Some(((r1 + m1 * k as i128).rem_euclid(period), period)))
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(gcd_of(&[12, 18, 27]), 3);
+        assert_eq!(lcm_of(&[2, 3, 4, 5]), Some(60));
+        assert_eq!((gcd_of(&[]), lcm_of(&[])), (0, Some(1)));
+        assert_eq!(lcm(0, u64::MAX), Some(0));
+        assert_eq!(lcm(1 << 40, 1 << 41), Some(1 << 41));
+        assert_eq!(lcm((1 << 40) + 15, 1 << 40), None);
+        assert_eq!(lcm_of(&[u64::MAX - 58, u64::MAX - 60]), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok(Some((23, 105))));
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Ok(Some((11, 12))));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), Ok(None));
+        assert_eq!(crt(&[(7, 5)]), Ok(Some((2, 5))));
+        assert_eq!(crt(&[(0, 0)]), Ok(None));
+        assert_eq!(crt(&[]), Ok(Some((0, 1))));
+    }
+
+    #[test]
+    fn test_crt_large_moduli() {
+        let overflow = Err(AocError::Overflow("the lcm of the moduli does not fit in a u64".to_string()));
+        let (m1, m2) = ((1 << 40) + 15, 1 << 40);
+        assert_eq!(crt(&[(1, m1), (0, m2)]), overflow);
+        assert_eq!(crt(&[(1, u64::MAX - 58), (2, u64::MAX - 60)]), overflow);
+        assert_eq!(crt(&[(1, 4), (2, 6), (1, u64::MAX)]), Ok(None));
+
+        let (x, period) = crt(&[(1, (1 << 31) + 11), (5, 1 << 31)]).unwrap().unwrap();
+        assert_eq!((x % ((1 << 31) + 11), x % (1 << 31)), (1, 5));
+        assert_eq!(period, ((1 << 31) + 11) * (1 << 31));
+        assert_eq!(crt(&[(u64::MAX - 1, u64::MAX)]), Ok(Some((u64::MAX - 1, u64::MAX))));
+        assert_eq!(crt(&[(3, 1), (u64::MAX - 1, u64::MAX)]), Ok(Some((u64::MAX - 1, u64::MAX))));
+    }
+
+    #[test]
+    fn test_crt_matches_brute_force() {
+        for m1 in 1..12u64 {
+            for m2 in 1..12u64 {
+                for (r1, r2) in [(0, 0), (1, 2), (5, 3), (7, 11)] {
+                    let expected = (0..m1 * m2).find(|x| x % m1 == r1 % m1 && x % m2 == r2 % m2);
+                    assert_eq!(crt(&[(r1, m1), (r2, m2)]).unwrap().map(|(x, _)| x), expected, "{r1} mod {m1}, {r2} mod {m2}");
+                }
+            }
+        }
+    }
+}
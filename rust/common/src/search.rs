@@ -0,0 +1,196 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The nodes from the start to the goal, both included, and the cost of moving along them.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Breadth-first search from `start`, stopping at the first node `is_goal` accepts.
+/// The cost is the number of steps.
+pub fn bfs<N, I, F, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<Path<N, usize>>
+    where N: Eq + Hash + Clone,
+          I: IntoIterator<Item=N>,
+          F: FnMut(&N) -> I,
+          G: FnMut(&N) -> bool
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = walk_back(&parents, node);
+            return Some(Path { cost: nodes.len() - 1, nodes });
+        }
+        for next in neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Steps from `start` to every node reachable from it.
+pub fn reachable<N, I, F>(start: N, mut neighbours: F) -> HashMap<N, usize>
+    where N: Eq + Hash + Clone,
+          I: IntoIterator<Item=N>,
+          F: FnMut(&N) -> I
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(&node) {
+            distances.entry(next.clone()).or_insert_with(|| {
+                queue.push_back(next);
+                distance + 1
+            });
+        }
+    }
+    distances
+}
+
+/// Cheapest path from `start` to a node `is_goal` accepts, where `neighbours`
+/// yields the next nodes with the cost of moving there.
+pub fn dijkstra<N, C, I, F, G>(start: N, neighbours: F, is_goal: G) -> Option<Path<N, C>>
+    where N: Eq + Hash + Clone,
+          C: Ord + Copy + Default + Add<Output=C>,
+          I: IntoIterator<Item=(N, C)>,
+          F: FnMut(&N) -> I,
+          G: FnMut(&N) -> bool
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], exploring nodes in order of cost so far plus `heuristic`.
+/// The path is the cheapest one as long as `heuristic` never overestimates the
+/// remaining cost.
+pub fn astar<N, C, I, F, H, G>(start: N, mut neighbours: F, mut heuristic: H, mut is_goal: G) -> Option<Path<N, C>>
+    where N: Eq + Hash + Clone,
+          C: Ord + Copy + Default + Add<Output=C>,
+          I: IntoIterator<Item=(N, C)>,
+          F: FnMut(&N) -> I,
+          H: FnMut(&N) -> C,
+          G: FnMut(&N) -> bool
+{
+    // Nodes are kept in `nodes` so the heap only orders costs and indices.
+    let mut nodes = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut best: Vec<(C, Option<usize>)> = vec![(C::default(), None)];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        if cost > best[idx].0 {
+            continue;
+        }
+        if is_goal(&nodes[idx]) {
+            let mut path = vec![idx];
+            while let Some(parent) = best[*path.last().unwrap()].1 {
+                path.push(parent);
+            }
+            return Some(Path { nodes: path.into_iter().rev().map(|idx| nodes[idx].clone()).collect(), cost });
+        }
+
+        for (next, step) in neighbours(&nodes[idx]) {
+            let next_cost = cost + step;
+            let next_idx = match indices.get(&next) {
+                Some(&next_idx) if best[next_idx].0 <= next_cost => continue,
+                Some(&next_idx) => next_idx,
+                None => {
+                    nodes.push(next.clone());
+                    best.push((next_cost, None));
+                    indices.insert(next, nodes.len() - 1);
+                    nodes.len() - 1
+                }
+            };
+            best[next_idx] = (next_cost, Some(idx));
+            heap.push(Reverse((next_cost + heuristic(&nodes[next_idx]), next_cost, next_idx)));
+        }
+    }
+    None
+}
+
+fn walk_back<N: Eq + Hash + Clone>(parents: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(Some(parent)) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::input::aoc_input_from_str;
+
+    use super::*;
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(&aoc_input_from_str("..#....\n.##.##.\n....#..\n.#.####\n...#..."), |c| Some(c == '#')).unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<bool>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbours4(position).filter(|next| grid.get(*next) == Some(&false)).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let path = bfs((0, 0), |position| open_neighbours(&grid, *position), |position| *position == (6, 0)).unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!((path.nodes.first(), path.nodes.last(), path.nodes.len()), (Some(&(0, 0)), Some(&(6, 0)), 11));
+        assert!(path.nodes.windows(2).all(|step| step[0].0.abs_diff(step[1].0) + step[0].1.abs_diff(step[1].1) == 1));
+
+        assert_eq!(bfs((0, 0), |position| open_neighbours(&grid, *position), |position| *position == (6, 4)), None);
+        assert_eq!(bfs((0, 0), |_| Vec::new(), |_| true), Some(Path { nodes: vec![(0, 0)], cost: 0 }));
+    }
+
+    #[test]
+    fn test_reachable() {
+        let grid = maze();
+        let distances = reachable((0, 0), |position| open_neighbours(&grid, *position));
+        assert_eq!(distances.get(&(6, 0)), Some(&10));
+        assert_eq!(distances.get(&(6, 4)), None);
+        assert_eq!(distances.len(), 20);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = HashMap::from([
+            ("a", vec![("b", 7), ("c", 9), ("f", 14)]),
+            ("b", vec![("c", 10), ("d", 15)]),
+            ("c", vec![("d", 11), ("f", 2)]),
+            ("d", vec![("e", 6)]),
+            ("f", vec![("e", 9)]),
+        ]);
+        let neighbours = |node: &&str| graph.get(node).cloned().unwrap_or_default();
+
+        assert_eq!(dijkstra("a", neighbours, |node| *node == "e"), Some(Path { nodes: vec!["a", "c", "f", "e"], cost: 20 }));
+        assert_eq!(dijkstra("e", neighbours, |node| *node == "a"), None);
+    }
+
+    #[test]
+    fn test_astar_stops_at_goal() {
+        let grid = maze();
+        let goal = (6, 0);
+        let mut expanded = 0;
+        let path = astar((0, 0),
+                         |position| {
+                             expanded += 1;
+                             open_neighbours(&grid, *position).into_iter().map(|next| (next, 1))
+                         },
+                         |position: &(usize, usize)| position.0.abs_diff(goal.0) + position.1.abs_diff(goal.1),
+                         |position| *position == goal).unwrap();
+
+        assert_eq!(path.cost, 10);
+        assert!(expanded < reachable((0, 0), |position| open_neighbours(&grid, *position)).len());
+    }
+}
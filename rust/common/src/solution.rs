@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::bench::{PartTimings, Stats, time};
+use crate::error::AocResult;
+use crate::input::load_aoc_input;
+
+/// One day of Advent of Code. The lines of the puzzle input are parsed once
+/// into `Input`, which both parts then solve.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &[String]) -> AocResult<Self::Input>;
+    fn part_one(&self, input: &Self::Input) -> AocResult<Self::Answer1>;
+    fn part_two(&self, input: &Self::Input) -> AocResult<Self::Answer2>;
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{s}', expected 1 or 2"))
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Object safe view of a [`Solution`] with its answers rendered as text, so
+/// days with different answer types can be registered side by side.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+    fn run(&self, part: Part, input: &[String]) -> AocResult<String>;
+    /// Parses and solves `part` `runs` times, timing both phases separately.
+    fn bench(&self, part: Part, input: &[String], runs: usize) -> AocResult<PartTimings>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, part: Part, input: &[String]) -> AocResult<String> {
+        let input = self.parse(input)?;
+        solve(self, part, &input)
+    }
+
+    fn bench(&self, part: Part, input: &[String], runs: usize) -> AocResult<PartTimings> {
+        let mut parse_samples = Vec::with_capacity(runs);
+        let mut solve_samples = Vec::with_capacity(runs);
+        let mut answer = String::new();
+
+        for _ in 0..runs.max(1) {
+            let (parsed, elapsed) = time(|| self.parse(input));
+            parse_samples.push(elapsed);
+            let parsed = parsed?;
+
+            let (solved, elapsed) = time(|| solve(self, part, &parsed));
+            solve_samples.push(elapsed);
+            answer = solved?;
+        }
+
+        Ok(PartTimings {
+            day: S::DAY,
+            part,
+            runs: parse_samples.len(),
+            answer,
+            parse: Stats::from_samples(&mut parse_samples),
+            solve: Stats::from_samples(&mut solve_samples),
+        })
+    }
+}
+
+fn solve<S: Solution>(solution: &S, part: Part, input: &S::Input) -> AocResult<String> {
+    match part {
+        Part::One => solution.part_one(input).map(|answer| answer.to_string()),
+        Part::Two => solution.part_two(input).map(|answer| answer.to_string()),
+    }
+}
+
+/// Prints the answers to both parts for the input in `input_file`.
+pub fn print_answers<S: Solution>(solution: &S, input_file: &str) -> AocResult<()> {
+    let input = solution.parse(&load_aoc_input(input_file)?)?;
+    println!("{}", solution.part_one(&input)?);
+    println!("{}", solution.part_two(&input)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        const DAY: u8 = 42;
+
+        type Input = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(&self, input: &[String]) -> AocResult<Vec<String>> {
+            Ok(input.to_vec())
+        }
+
+        fn part_one(&self, input: &Vec<String>) -> AocResult<usize> {
+            Ok(input.len())
+        }
+
+        fn part_two(&self, input: &Vec<String>) -> AocResult<String> {
+            Ok(input.join("-"))
+        }
+    }
+
+    #[test]
+    fn test_part_parse() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("two".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solutions: Vec<Box<dyn DynSolution>> = vec![Box::new(LineCount)];
+        let input = vec!["a".to_string(), "b".to_string()];
+
+        assert_eq!(solutions[0].day(), 42);
+        assert_eq!(solutions[0].run(Part::One, &input), Ok("2".to_string()));
+        assert_eq!(solutions[0].run(Part::Two, &input), Ok("a-b".to_string()));
+    }
+
+    #[test]
+    fn test_bench() {
+        let input = vec!["a".to_string(), "b".to_string()];
+        let timings = LineCount.bench(Part::Two, &input, 3).unwrap();
+
+        assert_eq!((timings.day, timings.part, timings.runs), (42, Part::Two, 3));
+        assert_eq!(timings.answer, "a-b");
+        assert!(timings.parse.min <= timings.parse.median && timings.parse.median <= timings.parse.max);
+    }
+}
@@ -0,0 +1,295 @@
+use common::{AocResult, Solution};
+
+pub mod extractor {
+    use std::cmp::Reverse;
+    use std::collections::HashMap;
+
+    use aho_corasick::{AhoCorasick, Anchored, Input, MatchKind, StartKind};
+    use fancy_regex::{CaptureMatches, Regex};
+
+    use common::{AocError, AocResult, parse_lines};
+
+    pub const ENGLISH: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    pub trait Extractor {
+        fn parse_line(&self, line: &str) -> AocResult<(u32, u32)>;
+    }
+
+    /// Collects the words which stand for a value in a line, and compiles them into
+    /// an [`Extractor`] finding the first and the last of them.
+    #[derive(Default, Clone)]
+    pub struct ExtractorBuilder {
+        words: HashMap<String, u32>,
+    }
+
+    impl ExtractorBuilder {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Every decimal digit stands for itself.
+        pub fn digits(self) -> Self {
+            (0..10).fold(self, |builder, digit| builder.word(&digit.to_string(), digit))
+        }
+
+        /// `word` stands for `value`, replacing what it stood for before. Empty
+        /// words are left out, as they would be found everywhere.
+        pub fn word(mut self, word: &str, value: u32) -> Self {
+            if !word.is_empty() {
+                self.words.insert(word.to_string(), value);
+            }
+            self
+        }
+
+        /// The words stand for consecutive values, starting at `first`.
+        pub fn words(self, first: u32, words: &[&str]) -> Self {
+            words.iter().zip(first..).fold(self, |builder, (word, value)| builder.word(word, value))
+        }
+
+        /// The English words for one to nine.
+        pub fn english(self) -> Self {
+            self.words(1, &ENGLISH)
+        }
+
+        /// Where two words start at the same place, the longest one is taken.
+        pub fn build(&self) -> RegexExtractor {
+            let mut words: Vec<&String> = self.words.keys().collect();
+            words.sort_by_key(|word| (Reverse(word.len()), *word));
+            let alternatives: Vec<String> = words.iter().map(|word| fancy_regex::escape(word).into_owned()).collect();
+
+            // A lookahead matches without consuming the word, so overlapping words
+            // such as `eightwo` are all found.
+            let pattern = if alternatives.is_empty() { "(?!)".to_string() } else { format!("(?=({}))", alternatives.join("|")) };
+            RegexExtractor { re: Regex::new(&pattern).expect("escaped words form a valid pattern"), words: self.words.clone() }
+        }
+
+        /// Finds the same words as [`ExtractorBuilder::build`] without a regex. The
+        /// first word takes time linear in the line whatever the size of the
+        /// vocabulary; the last takes up to one anchored match per offset after the
+        /// first word, so its worst case grows with the line length times the
+        /// longest word.
+        pub fn build_automaton(&self) -> AutomatonExtractor {
+            let (words, values): (Vec<&String>, Vec<u32>) = self.words.iter().map(|(word, value)| (word, *value)).unzip();
+
+            AutomatonExtractor {
+                forward: AhoCorasick::builder().match_kind(MatchKind::LeftmostLongest).build(&words)
+                    .expect("the vocabulary fits in an automaton"),
+                anchored: AhoCorasick::builder().match_kind(MatchKind::LeftmostLongest).start_kind(StartKind::Anchored)
+                    .build(&words)
+                    .expect("the vocabulary fits in an automaton"),
+                values,
+            }
+        }
+    }
+
+    pub struct RegexExtractor {
+        re: Regex,
+        words: HashMap<String, u32>,
+    }
+
+    impl Extractor for RegexExtractor {
+        fn parse_line(&self, line: &str) -> AocResult<(u32, u32)> {
+            let (first, last) = get_bounding_matches(&mut self.re.captures_iter(line))?;
+            Ok((self.words[&first], self.words[&last]))
+        }
+    }
+
+    /// Scans forward for the first word, then tries each start offset from the end
+    /// of the line for the last, so only the ends of the line are read when words
+    /// are frequent.
+    pub struct AutomatonExtractor {
+        forward: AhoCorasick,
+        /// Matches a word only where the search starts.
+        anchored: AhoCorasick,
+        /// The value of each word, by pattern id in both automatons.
+        values: Vec<u32>,
+    }
+
+    impl Extractor for AutomatonExtractor {
+        fn parse_line(&self, line: &str) -> AocResult<(u32, u32)> {
+            let first = self.forward.find(line).ok_or_else(|| AocError::bad_line(1, "no digit found"))?;
+
+            // The last word starts no earlier than the first, and of the words
+            // starting there the longest wins, as it does going forward.
+            let last = (first.start() + 1..line.len()).rev()
+                .find_map(|start| self.anchored.find(Input::new(line).range(start..).anchored(Anchored::Yes)))
+                .unwrap_or(first);
+
+            Ok((self.values[first.pattern()], self.values[last.pattern()]))
+        }
+    }
+
+    pub fn get_bounding_matches(matches: &mut CaptureMatches) -> AocResult<(String, String)> {
+        let mut matches = matches.filter_map(|capture| capture.ok());
+        let first = match matches.next() {
+            Some(capture) => String::from(&capture[1]),
+            None => return Err(AocError::bad_line(1, "no digit found"))
+        };
+        let second = if let Some(value) = matches.last() {
+            String::from(&value[1])
+        } else {
+            first.clone()
+        };
+        Ok((first, second))
+    }
+
+    pub fn compute(input: &[String], extractor: &impl Extractor) -> AocResult<u32> {
+        let values = parse_lines(input, |l| extractor.parse_line(l))?;
+        Ok(values.iter()
+            .map(|(first, last)| 10 * first + last)
+            .sum())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_other_vocabularies() {
+            let german = ExtractorBuilder::new().digits().words(1, &["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"]).build();
+            assert_eq!(german.parse_line("xzweinsfünf"), Ok((2, 5)));
+            assert_eq!(german.parse_line("sieben3acht"), Ok((7, 8)));
+
+            let tens = ExtractorBuilder::new().english().word("zero", 0).word("twenty", 20).word("seventeen", 17).build();
+            assert_eq!(tens.parse_line("zerotwentyone"), Ok((0, 1)));
+            assert_eq!(tens.parse_line("aseventeen"), Ok((17, 17)));
+            assert_eq!(tens.parse_line("twenty7"), Ok((20, 20)));
+
+            let reversed = ExtractorBuilder::new().words(1, &["eno", "owt", "eerht"]).build();
+            assert_eq!(reversed.parse_line("eerhtwo"), Ok((3, 3)));
+            assert_eq!(reversed.parse_line("owteno"), Ok((2, 1)));
+        }
+
+        #[test]
+        fn test_empty_vocabulary() {
+            let extractor = ExtractorBuilder::new().word("", 1).build();
+            assert_eq!(extractor.parse_line("one1"), Err(AocError::bad_line(1, "no digit found")));
+            let extractor = ExtractorBuilder::new().word("", 1).build_automaton();
+            assert_eq!(extractor.parse_line("one1"), Err(AocError::bad_line(1, "no digit found")));
+        }
+
+        #[test]
+        fn test_automaton_matches_regex() {
+            let builder = ExtractorBuilder::new().digits().english()
+                .word("seventeen", 17).word("abcd", 40).word("bc", 23).word("twenty", 20);
+            let (regex, automaton) = (builder.build(), builder.build_automaton());
+
+            for line in ["eightwo", "xtwone3four", "oneight", "seventeen", "aseventeenseven", "abcd", "zabcdx",
+                         "bcd", "twentyone", "1", "nothing", "sevenine", "fünf3ü", "", "twone"] {
+                assert_eq!(automaton.parse_line(line), regex.parse_line(line), "{line}");
+            }
+            assert_eq!(automaton.parse_line("abcd"), Ok((40, 23)));
+            assert_eq!(automaton.parse_line("sevenseventeen"), Ok((7, 17)));
+        }
+
+        #[test]
+        fn test_automaton_on_long_lines() {
+            let extractor = ExtractorBuilder::new().digits().english().build_automaton();
+            let line = format!("two{}nineight", "x".repeat(1 << 20));
+            assert_eq!(extractor.parse_line(&line), Ok((2, 8)));
+        }
+
+        #[test]
+        fn test_special_characters() {
+            let extractor = ExtractorBuilder::new().word("a+b", 4).word(".", 2).build();
+            assert_eq!(extractor.parse_line("aab a+b x.y"), Ok((4, 2)));
+        }
+    }
+}
+
+mod exercise1 {
+    use common::AocResult;
+
+    use crate::extractor::{ExtractorBuilder, compute as common_compute};
+
+    pub fn compute(input: &[String]) -> AocResult<u32> {
+        common_compute(input, &ExtractorBuilder::new().digits().build_automaton())
+    }
+
+    #[cfg(test)]
+    mod tests{
+        use common::load_aoc_input;
+
+        use crate::extractor::Extractor;
+
+        use super::*;
+
+        #[test]
+        fn test_extractor() {
+            let extractor = ExtractorBuilder::new().digits().build_automaton();
+
+            assert_eq!(extractor.parse_line("1234"), Ok((1,4)));
+            assert_eq!(extractor.parse_line("1"), Ok((1,1)));
+            assert_eq!(extractor.parse_line("sadsa1das23da4dasda"), Ok((1,4)));
+            assert_eq!(extractor.parse_line("asdas1dasda"), Ok((1,1)));
+            assert!(extractor.parse_line("nodigits").is_err());
+        }
+        #[test]
+        fn test_with_example() {
+            assert_eq!(compute(&load_aoc_input("test_data/e1.txt").unwrap()), Ok(142))
+        }
+    }
+
+}
+
+mod exercise2 {
+    use common::AocResult;
+
+    use crate::extractor::{ExtractorBuilder, compute as common_compute};
+
+    pub fn compute(input: &[String]) -> AocResult<u32> {
+        common_compute(input, &ExtractorBuilder::new().digits().english().build_automaton())
+    }
+
+    #[cfg(test)]
+    mod tests{
+        use common::load_aoc_input;
+
+        use crate::extractor::Extractor;
+
+        use super::*;
+
+        #[test]
+        fn test_extractor() {
+            let extractor = ExtractorBuilder::new().digits().english().build_automaton();
+
+            assert_eq!(extractor.parse_line("1234"), Ok((1,4)));
+            assert_eq!(extractor.parse_line("1"), Ok((1,1)));
+            assert_eq!(extractor.parse_line("sadsa1das23da4dasda"), Ok((1,4)));
+            assert_eq!(extractor.parse_line("asdas1dasda"), Ok((1,1)));
+            assert_eq!(extractor.parse_line("one"), Ok((1,1)));
+            assert_eq!(extractor.parse_line("onetwo"), Ok((1,2)));
+            assert_eq!(extractor.parse_line("some1onemore"), Ok((1,1)));
+            assert_eq!(extractor.parse_line("fancyeightwoandmore"), Ok((8,2)));
+        }
+        #[test]
+        fn test_with_example() {
+            assert_eq!(compute(&load_aoc_input("test_data/e2.txt").unwrap()), Ok(281))
+        }
+    }
+
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &[String]) -> AocResult<Vec<String>> {
+        Ok(input.to_vec())
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> AocResult<u32> {
+        exercise1::compute(input)
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> AocResult<u32> {
+        exercise2::compute(input)
+    }
+}
+
+
@@ -0,0 +1,401 @@
+use common::{AocResult, Solution};
+
+use crate::map::Map;
+
+mod map {
+    use std::fmt::{Debug, Formatter};
+    use common::{AocError, AocResult, Grid};
+    use crate::map::Direction::{East, North, South, West};
+
+    #[derive(PartialEq, Debug)]
+    pub enum MapNode {
+        Empty,
+        NorthSouth,
+        EastWest,
+        NorthEast,
+        NorthWest,
+        SouthEast,
+        SouthWest,
+        StartPoint,
+    }
+
+    impl MapNode {
+        pub fn to_display(&self) -> char {
+            match *self {
+                MapNode::Empty => '\u{00B7}',
+                MapNode::NorthSouth => '\u{2551}',
+                MapNode::EastWest => '\u{2550}',
+                MapNode::NorthEast => '\u{255A}',
+                MapNode::NorthWest => '\u{255D}',
+                MapNode::SouthEast => '\u{2554}',
+                MapNode::SouthWest => '\u{2557}',
+                MapNode::StartPoint => 'S',
+            }
+        }
+        /// Directions a pipe leads to; the start point may lead anywhere.
+        pub fn exits(&self) -> &'static [Direction] {
+            match self {
+                MapNode::Empty => &[],
+                MapNode::NorthSouth => &[North, South],
+                MapNode::EastWest => &[East, West],
+                MapNode::NorthEast => &[North, East],
+                MapNode::NorthWest => &[North, West],
+                MapNode::SouthEast => &[South, East],
+                MapNode::SouthWest => &[South, West],
+                MapNode::StartPoint => &[East, South, West, North],
+            }
+        }
+
+        fn from_char(c: &char) -> Option<Self> {
+            match c {
+                '.' => Some(MapNode::Empty),
+                '|' => Some(MapNode::NorthSouth),
+                '-' => Some(MapNode::EastWest),
+                'L' => Some(MapNode::NorthEast),
+                'J' => Some(MapNode::NorthWest),
+                '7' => Some(MapNode::SouthWest),
+                'F' => Some(MapNode::SouthEast),
+                'S' => Some(MapNode::StartPoint),
+                _ => None
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Direction {
+        South,
+        North,
+        East,
+        West,
+    }
+
+    impl From<&Direction> for (isize, isize) {
+        fn from(value: &Direction) -> Self {
+            match value {
+                East => (1, 0),
+                South => (0, 1),
+                West => (-1, 0),
+                North => (0, -1),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Critter {
+        pub pos: (usize, usize),
+        pub direction: Direction,
+    }
+
+    impl Critter {
+        pub fn new(pos: (usize, usize), direction: Direction) -> Self {
+            Critter { pos, direction }
+        }
+    }
+
+    #[derive(PartialEq)]
+    pub struct Map {
+        pub map: Grid<MapNode>,
+        pub start_point: (usize, usize),
+    }
+
+
+    impl Map {
+        pub fn parse(lines: Vec<String>) -> AocResult<Self> {
+            let map = Grid::parse(&lines, |c| MapNode::from_char(&c))?;
+            let start_point = map.iter()
+                .find(|(_, node)| **node == MapNode::StartPoint)
+                .map(|(position, _)| position)
+                .ok_or_else(|| AocError::UnexpectedEnd("start point 'S'".to_string()))?;
+
+            Ok(Self { map, start_point })
+        }
+
+        pub fn get(&self, x: usize, y: usize) -> Option<&MapNode> {
+            self.map.get((x, y))
+        }
+
+        pub fn get_next_position(&self, x: &usize, y: &usize, direction: &Direction) -> Result<((usize, usize), Direction), String> {
+            let new_pos = self.map.offset((*x, *y), direction.into())
+                .ok_or_else(|| "moving off the map".to_string())?;
+            let next_node = self.map.get(new_pos);
+            match direction {
+                East => match next_node {
+                    Some(MapNode::EastWest) => {
+                        Ok((new_pos, East))
+                    }
+                    Some(MapNode::NorthWest) => {
+                        Ok((new_pos, North))
+                    }
+                    Some(MapNode::SouthWest) => {
+                        Ok((new_pos, South))
+                    }
+                    Some(MapNode::StartPoint) => {
+                        Ok((new_pos, East))
+                    }
+                    _ => Err(format!("Could not move to {direction:?} {new_pos:?} {next_node:?}"))
+                },
+                South => match next_node {
+                    Some(MapNode::NorthSouth) => {
+                        Ok((new_pos, South))
+                    }
+                    Some(MapNode::NorthEast) => {
+                        Ok((new_pos, East))
+                    }
+                    Some(MapNode::NorthWest) => {
+                        Ok((new_pos, West))
+                    }
+                    Some(MapNode::StartPoint) => {
+                        Ok((new_pos, South))
+                    }
+                    _ => Err(format!("Could not move to {direction:?} {new_pos:?} {next_node:?}"))
+                },
+                West => match next_node {
+                    Some(MapNode::EastWest) => {
+                        Ok((new_pos, West))
+                    }
+                    Some(MapNode::NorthEast) => {
+                        Ok((new_pos, North))
+                    }
+                    Some(MapNode::SouthEast) => {
+                        Ok((new_pos, South))
+                    }
+                    Some(MapNode::StartPoint) => {
+                        Ok((new_pos, West))
+                    }
+                    _ => Err(format!("Could not move to {direction:?} {new_pos:?} {next_node:?}"))
+                },
+                North => match next_node {
+                    Some(MapNode::NorthSouth) => {
+                        Ok((new_pos, North))
+                    }
+                    Some(MapNode::SouthEast) => {
+                        Ok((new_pos, East))
+                    }
+                    Some(MapNode::SouthWest) => {
+                        Ok((new_pos, West))
+                    }
+                    Some(MapNode::StartPoint) => {
+                        Ok((new_pos, North))
+                    }
+                    _ => Err(format!("Could not move to {direction:?} {new_pos:?} {next_node:?}"))
+                }
+            }
+        }
+
+        /// Positions the pipe at `(x, y)` connects to.
+        pub fn connections(&self, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+            self.get(x, y).map_or(&[][..], MapNode::exits).iter()
+                .filter_map(|direction| self.get_next_position(&x, &y, direction).ok())
+                .map(|(position, _)| position)
+                .collect()
+        }
+
+        pub fn get_possible_start_critters(&self) -> Vec<Critter> {
+            let (start_x, start_y) = self.start_point;
+            let mut result = Vec::new();
+            for direction in [East, South, West, North] {
+                if self.get_next_position(&start_x, &start_y, &direction).is_ok() {
+                    result.push(Critter::new(self.start_point, direction));
+                }
+            }
+
+            result
+        }
+    }
+
+
+    impl Debug for Map {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            writeln!(f)?;
+            writeln!(f, "{}", self.map.render(MapNode::to_display))
+        }
+    }
+
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use crate::map::MapNode::{EastWest, Empty, NorthEast, NorthSouth, NorthWest, SouthWest, StartPoint};
+        use super::*;
+
+        #[test]
+        fn test_parse() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            assert_eq!(Map::parse(input), Ok(Map {
+                map: Grid::from_rows(vec![
+                    vec![Empty, Empty, Empty, Empty, Empty],
+                    vec![Empty, StartPoint, EastWest, SouthWest, Empty],
+                    vec![Empty, NorthSouth, Empty, NorthSouth, Empty],
+                    vec![Empty, NorthEast, EastWest, NorthWest, Empty],
+                    vec![Empty, Empty, Empty, Empty, Empty],
+                ]),
+                start_point: (1, 1),
+            }));
+        }
+
+        #[test]
+        fn test_parse_error() {
+            assert_eq!(Map::parse(vec![".....".to_string(), ".S-x.".to_string()]),
+                       Err(AocError::BadLine { line: 2, column: 4, message: "invalid char 'x'".to_string() }));
+            assert_eq!(Map::parse(vec![".....".to_string(), ".-.".to_string()]),
+                       Err(AocError::BadLine { line: 2, column: 4, message: "expected 5 cells, found 3".to_string() }));
+            assert_eq!(Map::parse(vec![".....".to_string()]),
+                       Err(AocError::UnexpectedEnd("start point 'S'".to_string())));
+        }
+
+        #[test]
+        fn test_connections() {
+            let map = Map::parse(load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+
+            assert_eq!(map.connections((1, 1)), vec![(2, 1), (1, 2)]);
+            assert_eq!(map.connections((3, 2)), vec![(3, 1), (3, 3)]);
+            assert_eq!(map.connections((0, 0)), vec![]);
+        }
+
+        #[test]
+        fn test_start_critters() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            let map = Map::parse(input).unwrap();
+
+            assert_eq!(map.get_possible_start_critters(),
+                       vec![Critter::new((1, 1), Direction::East),
+                            Critter::new((1, 1), Direction::South)]
+            );
+        }
+    }
+}
+
+mod exercise1 {
+    use common::reachable;
+    use crate::map::Map;
+
+    /// Steps along the loop to the point farthest from the start.
+    pub fn calculate(map: &Map) -> usize {
+        reachable(map.start_point, |position| map.connections(*position))
+            .into_values()
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_calculate() {
+            for (input_file, expected) in [
+                ("test_data/e1.txt", 4),
+                ("test_data/e2.txt", 4),
+                ("test_data/e3.txt", 8),
+            ] {
+                let map = Map::parse(load_aoc_input(input_file).unwrap()).unwrap();
+                assert_eq!(calculate(&map), expected)
+            }
+        }
+    }
+}
+
+mod exercise2 {
+    use common::{AocError, AocResult, reachable};
+    use crate::map::{Map, MapNode};
+    use crate::map::MapNode::{NorthEast, NorthSouth, NorthWest, SouthEast, SouthWest, EastWest};
+
+    /// The pipe hidden under the start point, found from the pipes connecting to it.
+    fn start_node(map: &Map) -> AocResult<MapNode> {
+        let directions: Vec<_> = map.get_possible_start_critters().into_iter().map(|critter| critter.direction).collect();
+        [NorthSouth, EastWest, NorthEast, NorthWest, SouthEast, SouthWest].into_iter()
+            .find(|node| directions.len() == 2 && node.exits().iter().all(|exit| directions.contains(exit)))
+            .ok_or_else(|| AocError::NoSolution(format!("start point 'S' connects to {} pipes, expected 2", directions.len())))
+    }
+
+    pub fn calculate(map: &Map) -> AocResult<usize> {
+        let start_node = start_node(map)?;
+        let route = reachable(map.start_point, |position| map.connections(*position));
+
+        let mut count: usize = 0;
+        let mut last_corner: Option<&MapNode> = None;
+
+        for (y, map_line) in map.map.rows().enumerate() {
+            let mut in_loop = false;
+            for (x, mut node) in map_line.iter().enumerate() {
+                if route.contains_key(&(x, y)) {
+                    if (x, y) == map.start_point {
+                        node = &start_node;
+                    }
+                    match node {
+                        NorthSouth => {
+                            in_loop = !in_loop;
+                        }
+                        NorthEast | SouthEast => {
+                            last_corner = Some(node);
+                        }
+                        NorthWest => {
+                            if let Some(SouthEast) = last_corner {
+                                in_loop = !in_loop;
+                            }
+                        }
+                        SouthWest => {
+                            if let Some(NorthEast) = last_corner {
+                                in_loop = !in_loop;
+                            }
+                        }
+                        _ => {}
+                    };
+                } else if in_loop {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_calculate() {
+            for (input_file, expected) in [
+                ("test_data/e4.txt", 4),
+                ("test_data/e5.txt", 8),
+                ("test_data/e6.txt", 10),
+                ("test_data/e7.txt", 4),
+            ] {
+                let map = Map::parse(load_aoc_input(input_file).unwrap()).unwrap();
+                assert_eq!(calculate(&map), Ok(expected))
+            }
+        }
+
+        #[test]
+        fn test_calculate_bad_start() {
+            for (lines, connected) in [([".....", ".S-S.", "....."], 1), (["..|..", ".-S-.", "..|.."], 4)] {
+                let map = Map::parse(lines.map(String::from).to_vec()).unwrap();
+                assert_eq!(calculate(&map), Err(AocError::NoSolution(format!("start point 'S' connects to {connected} pipes, expected 2"))));
+            }
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &[String]) -> AocResult<Map> {
+        Map::parse(input.to_vec())
+    }
+
+    fn part_one(&self, input: &Map) -> AocResult<usize> {
+        Ok(exercise1::calculate(input))
+    }
+
+    fn part_two(&self, input: &Map) -> AocResult<usize> {
+        exercise2::calculate(input)
+    }
+}
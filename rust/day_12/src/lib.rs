@@ -0,0 +1,428 @@
+use common::{AocResult, Solution};
+
+use crate::exercise1::{calculate, parse_records};
+
+pub mod decipher {
+    use derive_new::new;
+    use rand::Rng;
+
+    use crate::counter::CountTable;
+
+    /// Lazily yields the arrangements of a record in lexicographic order, in which
+    /// `#` comes before `.`. Skipping ahead costs no more than one step.
+    #[derive(PartialEq, Debug)]
+    pub struct Arrangements {
+        table: CountTable,
+        rank: usize,
+    }
+
+    impl Arrangements {
+        pub fn new(pattern: &str, chunks: &[usize]) -> Self {
+            Arrangements { table: CountTable::new(pattern, chunks), rank: 0 }
+        }
+
+        /// One of all the arrangements, each equally likely, or `None` when there are none.
+        pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<String> {
+            match self.table.total() {
+                0 => None,
+                total => self.table.unrank(rng.gen_range(0..total)),
+            }
+        }
+    }
+
+    impl Iterator for Arrangements {
+        type Item = String;
+
+        fn next(&mut self) -> Option<String> {
+            let fixture = self.table.unrank(self.rank)?;
+            self.rank += 1;
+            Some(fixture)
+        }
+
+        fn nth(&mut self, n: usize) -> Option<String> {
+            self.rank = self.rank.saturating_add(n).min(self.table.total());
+            self.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.table.total().saturating_sub(self.rank);
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl ExactSizeIterator for Arrangements {}
+
+    #[derive(PartialEq, Debug, new)]
+    pub struct DecipherState {
+        pattern: String,
+        fixture: String,
+        chunks: Vec<usize>,
+    }
+
+    /// Every arrangement of `chunks` matching `pattern`, built one by one.
+    /// Meant for checking small records; `counter` counts large ones.
+    pub fn arrangements(pattern: &str, chunks: &[usize]) -> Vec<String> {
+        let mut work_queue = vec![DecipherState::new(pattern.to_string(), String::new(), chunks.to_vec())];
+        let mut result = Vec::new();
+
+        while let Some(state) = work_queue.pop() {
+            for state in state.next_states() {
+                if state.is_terminal() {
+                    result.push(state.fixture);
+                } else {
+                    work_queue.push(state)
+                }
+            }
+        }
+
+        result.sort();
+        result
+    }
+
+    impl DecipherState {
+        pub fn next_states(&self) -> Vec<DecipherState> {
+            let remaining = self.pattern.len() - self.fixture.len();
+            if self.chunks.is_empty() {
+                // No more chuncks just fill in
+
+                let fixture = format!("{}{}", self.fixture, ".".repeat(remaining));
+                if self.validate(&fixture) {
+                    return vec![DecipherState { pattern: self.pattern.clone(), fixture, chunks: vec![] }];
+                } else {
+                    return vec![];
+                }
+            }
+
+            let next_chunk_length = *self.chunks.first().unwrap();
+            let chunk_txt = "#".repeat(next_chunk_length) + if self.chunks.len() > 1 { "." } else { "" };
+            let max_space_size: usize = self.pattern.len()
+                - self.fixture.len() // what we already have
+                - self.chunks.iter().sum::<usize>() // what sure we will have
+                - (self.chunks.len() - 1); // The minimal dot's between
+
+            let mut result = Vec::new();
+
+            for l in 0..=max_space_size {
+                let fixture = format!("{}{}{}", self.fixture, ".".repeat(l), chunk_txt);
+
+                if self.validate(&fixture) {
+                    result.push(DecipherState { pattern: self.pattern.clone(), fixture, chunks: Vec::from(&self.chunks[1..]) })
+                }
+            }
+
+            result
+        }
+
+        pub fn is_terminal(&self) -> bool {
+            self.fixture.len() == self.pattern.len()
+        }
+        fn validate(&self, fixture: &str) -> bool {
+            // we just validate after current fixture
+            self.pattern[self.fixture.len()..fixture.len()]
+                .chars()
+                .enumerate()
+                .filter(|(_, char)| char != &'?')
+                .all(|(offset, pattern_char)| pattern_char == fixture.as_bytes()[self.fixture.len() + offset] as char)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::HashMap;
+
+        use itertools::Itertools;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        use super::*;
+
+        #[test]
+        fn test_next_states() {
+            let pattern = "?###????????".to_string();
+            assert_eq!(DecipherState::new(pattern.clone(), ".###.".to_string(), vec![]).next_states(),
+                       vec![
+                           DecipherState::new(pattern.clone(), ".###........".to_string(), vec![])
+                       ]);
+            assert_eq!(DecipherState::new(pattern.clone(), ".###.".to_string(), vec![2]).next_states(),
+                       vec![
+                           DecipherState::new(pattern.clone(), ".###.##".to_string(), vec![]),
+                           DecipherState::new(pattern.clone(), ".###..##".to_string(), vec![]),
+                           DecipherState::new(pattern.clone(), ".###...##".to_string(), vec![]),
+                           DecipherState::new(pattern.clone(), ".###....##".to_string(), vec![]),
+                           DecipherState::new(pattern.clone(), ".###.....##".to_string(), vec![]),
+                           DecipherState::new(pattern.clone(), ".###......##".to_string(), vec![]),
+                       ]);
+            assert_eq!(DecipherState::new(pattern.clone(), ".###.".to_string(), vec![2, 3]).next_states(),
+                       vec![
+                           DecipherState::new(pattern.clone(), ".###.##.".to_string(), vec![3]),
+                           DecipherState::new(pattern.clone(), ".###..##.".to_string(), vec![3]),
+                       ]);
+            assert_eq!(DecipherState::new(pattern.clone(), "".to_string(), vec![3, 2, 1]).next_states(),
+                       vec![
+                           DecipherState::new(pattern.clone(), ".###.".to_string(), vec![2, 1]),
+                       ])
+        }
+
+        #[test]
+        fn test_lazy_arrangements() {
+            for (pattern, chunks) in [("?###????????", vec![3, 2, 1]), (".??..??...?##.?.??..??...?##.", vec![1, 1, 3, 1, 1, 3])] {
+                assert_eq!(Arrangements::new(pattern, &chunks).collect::<Vec<_>>(), arrangements(pattern, &chunks));
+            }
+
+            let mut lazy = Arrangements::new("?###????????", &[3, 2, 1]);
+            assert_eq!(lazy.len(), 10);
+            assert_eq!(lazy.nth(8), arrangements("?###????????", &[3, 2, 1]).into_iter().nth(8));
+            assert_eq!(lazy.len(), 1);
+            assert_eq!(lazy.nth(5), None);
+        }
+
+        #[test]
+        fn test_skip_without_enumerating() {
+            let unfolded = Itertools::intersperse(std::iter::repeat_n("?###????????", 5), "?").collect::<String>();
+            let chunks = [3, 2, 1].repeat(5);
+            let mut lazy = Arrangements::new(&unfolded, &chunks);
+            assert_eq!(lazy.len(), 506250);
+            let last = lazy.nth(506249).unwrap();
+            assert!(last.ends_with("....##.#"));
+            assert_eq!(lazy.next(), None);
+        }
+
+        #[test]
+        fn test_sample() {
+            let lazy = Arrangements::new("?###????????", &[3, 2, 1]);
+            let all = arrangements("?###????????", &[3, 2, 1]);
+            let mut rng = StdRng::seed_from_u64(12);
+            let mut hits: HashMap<String, usize> = HashMap::new();
+            for _ in 0..10_000 {
+                *hits.entry(lazy.sample(&mut rng).unwrap()).or_default() += 1;
+            }
+
+            assert_eq!(hits.len(), all.len());
+            assert!(hits.values().all(|count| (800..1200).contains(count)), "{hits:?}");
+            assert_eq!(lazy.sample(&mut StdRng::seed_from_u64(12)), lazy.sample(&mut StdRng::seed_from_u64(12)));
+            assert_eq!(Arrangements::new("#", &[2]).sample(&mut rng), None);
+        }
+
+        #[test]
+        fn test_arrangements() {
+            assert_eq!(arrangements("???.###", &[1, 1, 3]), vec!["#.#.###"]);
+            assert_eq!(arrangements("??", &[1]), vec!["#.", ".#"]);
+        }
+    }
+}
+
+mod counter {
+    /// Arrangement counts for every (pattern offset, remaining chunks) suffix of a record.
+    #[derive(PartialEq, Debug)]
+    pub struct CountTable {
+        pattern: Vec<u8>,
+        chunks: Vec<usize>,
+        counts: Vec<Vec<usize>>,
+    }
+
+    impl CountTable {
+        pub fn new(pattern: &str, chunks: &[usize]) -> Self {
+            let mut table = CountTable {
+                pattern: pattern.as_bytes().to_vec(),
+                chunks: chunks.to_vec(),
+                counts: vec![vec![0; chunks.len() + 1]; pattern.len() + 1],
+            };
+
+            for offset in (0..=pattern.len()).rev() {
+                for chunk in (0..=chunks.len()).rev() {
+                    table.counts[offset][chunk] = match table.pattern.get(offset) {
+                        _ if chunk == chunks.len() => usize::from(!table.pattern[offset..].contains(&b'#')),
+                        None => 0,
+                        Some(&spring) => {
+                            let as_operational = if spring != b'#' { table.counts[offset + 1][chunk] } else { 0 };
+                            as_operational + table.damaged(offset, chunk)
+                        }
+                    };
+                }
+            }
+            table
+        }
+
+        pub fn total(&self) -> usize {
+            self.counts[0][0]
+        }
+
+        /// The arrangement at `rank` in lexicographic order, in which `#` comes before `.`.
+        pub fn unrank(&self, mut rank: usize) -> Option<String> {
+            if rank >= self.total() {
+                return None;
+            }
+
+            let (mut offset, mut chunk) = (0, 0);
+            let mut fixture = String::with_capacity(self.pattern.len());
+            while offset < self.pattern.len() {
+                let damaged = if chunk < self.chunks.len() { self.damaged(offset, chunk) } else { 0 };
+                if rank < damaged {
+                    let end = offset + self.chunks[chunk];
+                    fixture.push_str(&"#".repeat(self.chunks[chunk]));
+                    if end < self.pattern.len() {
+                        fixture.push('.');
+                    }
+                    (offset, chunk) = (self.after(end), chunk + 1);
+                } else {
+                    rank -= damaged;
+                    fixture.push('.');
+                    offset += 1;
+                }
+            }
+            Some(fixture)
+        }
+
+        /// Arrangements starting with the damaged group `chunk` at `offset`.
+        fn damaged(&self, offset: usize, chunk: usize) -> usize {
+            let end = offset + self.chunks[chunk];
+            let fits = self.pattern.get(offset) != Some(&b'.')
+                && end <= self.pattern.len()
+                && !self.pattern[offset..end].contains(&b'.')
+                && self.pattern.get(end) != Some(&b'#');
+            if fits { self.counts[self.after(end)][chunk + 1] } else { 0 }
+        }
+
+        /// Offset following a damaged group ending at `end` and the operational spring after it.
+        fn after(&self, end: usize) -> usize {
+            (end + 1).min(self.pattern.len())
+        }
+    }
+
+    /// Number of ways to place damaged groups of the `chunks` sizes, in order, on `pattern`.
+    pub fn count_arrangements(pattern: &str, chunks: &[usize]) -> usize {
+        CountTable::new(pattern, chunks).total()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::decipher::arrangements;
+        use super::*;
+
+        const EXAMPLES: [(&str, &[usize], usize); 6] = [
+            ("???.###", &[1, 1, 3], 1),
+            (".??..??...?##.", &[1, 1, 3], 4),
+            ("?#?#?#?#?#?#?#?", &[1, 3, 1, 6], 1),
+            ("????.#...#...", &[4, 1, 1], 1),
+            ("????.######..#####.", &[1, 6, 5], 4),
+            ("?###????????", &[3, 2, 1], 10),
+        ];
+
+        #[test]
+        fn test_count_arrangements() {
+            for (pattern, chunks, expected) in EXAMPLES {
+                assert_eq!(count_arrangements(pattern, chunks), expected, "{pattern}");
+            }
+            assert_eq!(count_arrangements("", &[]), 1);
+            assert_eq!(count_arrangements("#", &[]), 0);
+            assert_eq!(count_arrangements("??", &[3]), 0);
+        }
+
+        #[test]
+        fn test_unrank() {
+            let table = CountTable::new("?###????????", &[3, 2, 1]);
+            assert_eq!(table.unrank(0), Some(".###.##.#...".to_string()));
+            assert_eq!(table.unrank(9), Some(".###....##.#".to_string()));
+            assert_eq!(table.unrank(10), None);
+        }
+
+        #[test]
+        fn test_count_matches_enumeration() {
+            for (pattern, chunks, _) in EXAMPLES {
+                let doubled = format!("{pattern}?{pattern}");
+                let chunks = [chunks, chunks].concat();
+                assert_eq!(count_arrangements(&doubled, &chunks), arrangements(&doubled, &chunks).len(), "{doubled}");
+            }
+        }
+    }
+}
+
+mod exercise1 {
+    use itertools::{Itertools, repeat_n};
+    use common::{AocError, AocResult, parse_lines, parse_token};
+    use crate::counter::count_arrangements;
+
+    fn parse_record(line: &str) -> AocResult<(String, Vec<usize>)> {
+        let mut split_line = line.split_whitespace();
+        let pattern = split_line.next().ok_or_else(|| AocError::missing(line, "spring pattern"))?;
+        if let Some((idx, c)) = pattern.chars().enumerate().find(|(_, c)| !matches!(c, '.' | '#' | '?')) {
+            return Err(AocError::bad_line(idx + 1, format!("invalid spring '{c}'")));
+        }
+
+        let chunks = split_line.next().ok_or_else(|| AocError::missing(line, "damaged group sizes"))?
+            .split(',')
+            .map(|n| parse_token(line, n))
+            .collect::<AocResult<_>>()?;
+        Ok((pattern.to_string(), chunks))
+    }
+
+    pub fn parse_records(input: &[String]) -> AocResult<Vec<(String, Vec<usize>)>> {
+        parse_lines(input, parse_record)
+    }
+
+    /// Repeats the record `times` times, joining the patterns with an unknown spring.
+    fn unfold((pattern, chunks): &(String, Vec<usize>), times: usize) -> (String, Vec<usize>) {
+        (Itertools::intersperse(repeat_n(pattern.as_str(), times), "?").collect(),
+         repeat_n(chunks, times).flatten().copied().collect())
+    }
+
+    pub fn calculate(records: &[(String, Vec<usize>)], unfold_records: bool) -> usize {
+        let times = if unfold_records { 5 } else { 1 };
+        records.iter()
+            .map(|record| {
+                let (pattern, chunks) = unfold(record, times);
+                count_arrangements(&pattern, &chunks)
+            })
+            .sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_parse_record() {
+            assert_eq!(parse_record("???.### 1,1,3"), Ok(("???.###".to_string(), vec![1, 1, 3])));
+            assert_eq!(parse_record("???.### 1,x,3"), Err(AocError::bad_line(11, "invalid value 'x': invalid digit found in string")));
+            assert_eq!(parse_record("??a.### 1,1,3"), Err(AocError::bad_line(3, "invalid spring 'a'")));
+            assert_eq!(parse_record("???.###"), Err(AocError::missing("???.###", "damaged group sizes")));
+        }
+
+        #[test]
+        fn test_unfold() {
+            assert_eq!(unfold(&(".#".to_string(), vec![1]), 5), (".#?.#?.#?.#?.#".to_string(), vec![1, 1, 1, 1, 1]));
+            assert_eq!(unfold(&(".#".to_string(), vec![1]), 1), (".#".to_string(), vec![1]));
+        }
+
+        #[test]
+        fn test_calculate(){
+            let records = parse_records(&load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+            assert_eq!(calculate(&records,false), 21);
+            assert_eq!(calculate(&records,true), 525152);
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    /// Spring patterns with their damaged group sizes.
+    type Input = Vec<(String, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &[String]) -> AocResult<Self::Input> {
+        parse_records(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<usize> {
+        Ok(calculate(input, false))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<usize> {
+        Ok(calculate(input, true))
+    }
+}
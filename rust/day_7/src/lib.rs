@@ -0,0 +1,519 @@
+use common::{AocResult, Solution};
+
+use crate::cards::{Hand, Jokers, Standard};
+use crate::exercise::{compute, parse_bids, ranked_table};
+
+mod cards {
+    use std::cmp::Ordering;
+    use std::fmt::{Debug, Display, Formatter};
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use itertools::Itertools;
+
+    use common::{AocError, AocResult};
+
+    use crate::cards::HandClassification::{FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair};
+
+    #[derive(PartialEq, Debug, PartialOrd, Ord, Eq, Copy, Clone)]
+    pub enum HandClassification {
+        HighCard,
+        OnePair,
+        TwoPair,
+        ThreeOfAKind,
+        FullHouse,
+        FourOfAKind,
+        FiveOfAKind,
+    }
+
+    impl HandClassification {
+        /// The category of a five card hand, from how often each card occurs,
+        /// most frequent first.
+        pub fn of(signature: &[usize]) -> Self {
+            match signature {
+                [5, ..] => FiveOfAKind,
+                [4, ..] => FourOfAKind,
+                [3, 2, ..] => FullHouse,
+                [3, ..] => ThreeOfAKind,
+                [2, 2, ..] => TwoPair,
+                [2, ..] => OnePair,
+                _ => HighCard,
+            }
+        }
+    }
+
+    /// The rules of a camel cards variant: which cards there are and how they
+    /// rank, which of them are wild, how many make a hand, and how hands are
+    /// categorised and compared.
+    pub trait RuleSet {
+        /// Every card, from the weakest to the strongest.
+        const ORDER: &'static str;
+        /// Cards which stand in for whichever card makes the best hand. They keep
+        /// their own rank from [`RuleSet::ORDER`] when breaking ties.
+        const WILD: &'static str = "";
+        const HAND_SIZE: usize = 5;
+
+        /// What hands are ranked by before their cards are compared. A
+        /// `Vec<usize>` holding the signature itself works for any hand size, as
+        /// signatures compare by their most frequent card first.
+        type Category: Ord + Clone + Debug;
+
+        /// The category of a hand without wild cards, from its signature: how
+        /// often each card occurs, most frequent first.
+        fn category(signature: &[usize]) -> Self::Category;
+
+        /// The card ranks in the order they break ties between hands of the same
+        /// category.
+        fn tie_break(cards: &[u8]) -> Vec<u8> {
+            cards.to_vec()
+        }
+    }
+
+    /// Why a hand got its category.
+    #[derive(PartialEq, Debug)]
+    pub struct Explanation<C> {
+        pub category: C,
+        /// The 0-based position of each wild card with the card it stands in for.
+        pub substitutions: Vec<(usize, char)>,
+        /// The cards once substituted, with how often they occur, largest group
+        /// and then strongest card first.
+        pub groups: Vec<(char, usize)>,
+    }
+
+    /// What a hand ranks by: its category, then its tie-break cards.
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+    pub struct Strength<C> {
+        pub category: C,
+        pub tie_break: Vec<u8>,
+    }
+
+    /// The rules of part one, where `J` is a jack.
+    pub struct Standard;
+
+    impl RuleSet for Standard {
+        const ORDER: &'static str = "123456789TJQKA";
+
+        type Category = HandClassification;
+
+        fn category(signature: &[usize]) -> HandClassification {
+            HandClassification::of(signature)
+        }
+    }
+
+    /// The rules of part two, where `J` is a joker: wild, but the weakest card.
+    pub struct Jokers;
+
+    impl RuleSet for Jokers {
+        const ORDER: &'static str = "J123456789TQKA";
+        const WILD: &'static str = "J";
+
+        type Category = HandClassification;
+
+        fn category(signature: &[usize]) -> HandClassification {
+            HandClassification::of(signature)
+        }
+    }
+
+    pub struct Hand<R: RuleSet> {
+        /// Ranks of the cards, indices into [`RuleSet::ORDER`].
+        cards: Vec<u8>,
+        strength: Strength<R::Category>,
+        rules: PhantomData<R>,
+    }
+
+    /// How often each card occurs, most frequent first.
+    fn signature(cards: &[u8]) -> Vec<usize> {
+        cards.iter().counts().into_values().sorted().rev().collect()
+    }
+
+    impl<R: RuleSet> Hand<R> {
+        pub fn parse(input: &str) -> AocResult<Self> {
+            let cards = input.chars().enumerate().map(|(idx, c)| {
+                R::ORDER.chars().position(|card| card == c)
+                    .map(|rank| rank as u8)
+                    .ok_or_else(|| AocError::bad_line(idx + 1, format!("invalid card '{c}'")))
+            }).collect::<AocResult<Vec<u8>>>()?;
+            if cards.len() != R::HAND_SIZE {
+                return Err(AocError::bad_line(1, format!("expected {} cards in '{input}'", R::HAND_SIZE)));
+            }
+            let (_, category) = Self::best_substitution(&cards);
+            let strength = Strength { category, tie_break: R::tie_break(&cards) };
+            Ok(Self { cards, strength, rules: PhantomData })
+        }
+
+        fn card(rank: u8) -> char {
+            R::ORDER.chars().nth(rank as usize).unwrap_or('?')
+        }
+
+        fn is_wild(rank: u8) -> bool {
+            R::WILD.contains(Self::card(rank))
+        }
+
+        pub fn classify(&self) -> R::Category {
+            self.strength.category.clone()
+        }
+
+        /// Worked out once when the hand is read, so sorting hands is cheap.
+        pub fn strength(&self) -> &Strength<R::Category> {
+            &self.strength
+        }
+
+        /// The category of the hand, what its wild cards stood in for and the
+        /// groups of cards which made it.
+        pub fn explain(&self) -> Explanation<R::Category> {
+            let (substituted, category) = Self::best_substitution(&self.cards);
+            let substitutions = self.cards.iter().zip(&substituted).enumerate()
+                .filter(|(_, (&card, _))| Self::is_wild(card))
+                .map(|(idx, (_, &substitute))| (idx, Self::card(substitute)))
+                .collect();
+            let groups = substituted.iter().counts().into_iter()
+                .sorted_by(|(left, left_count), (right, right_count)| right_count.cmp(left_count).then(right.cmp(left)))
+                .map(|(&card, count)| (Self::card(card), count))
+                .collect();
+            Explanation { category, substitutions, groups }
+        }
+
+        /// The best category `cards` can make with the cards which make it,
+        /// trying every card the wild cards could usefully stand in for: one
+        /// already in the hand, or the strongest card when the hand is all wild.
+        /// Among equally good substitutions the strongest cards win.
+        fn best_substitution(cards: &[u8]) -> (Vec<u8>, R::Category) {
+            let wild: Vec<usize> = (0..cards.len()).filter(|&idx| Self::is_wild(cards[idx])).collect();
+            let mut candidates: Vec<u8> = cards.iter().copied().filter(|&card| !Self::is_wild(card)).unique().sorted().collect();
+            if candidates.is_empty() {
+                candidates.extend((0..R::ORDER.chars().count() as u8).rev().find(|&card| !Self::is_wild(card)));
+            }
+
+            candidates.into_iter()
+                .combinations_with_replacement(wild.len())
+                .map(|substitutes| {
+                    let mut cards = cards.to_vec();
+                    for (&idx, card) in wild.iter().zip(substitutes) {
+                        cards[idx] = card;
+                    }
+                    let category = R::category(&signature(&cards));
+                    (cards, category)
+                })
+                .max_by(|(_, left), (_, right)| left.cmp(right))
+                .unwrap_or_else(|| (cards.to_vec(), R::category(&signature(cards))))
+        }
+    }
+
+    impl<R: RuleSet> PartialEq for Hand<R> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cards == other.cards
+        }
+    }
+
+    impl<R: RuleSet> Eq for Hand<R> {}
+
+    impl<R: RuleSet> PartialOrd for Hand<R> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<R: RuleSet> Ord for Hand<R> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Hands of equal strength still differ when the rule set breaks ties
+            // on something other than the cards in order.
+            self.strength.cmp(&other.strength)
+                .then_with(|| self.cards.cmp(&other.cards))
+        }
+    }
+
+    impl<R: RuleSet> Display for Hand<R> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let cards: String = self.cards.iter().map(|&card| Self::card(card)).collect();
+            write!(f, "{cards}")
+        }
+    }
+
+    impl<R: RuleSet> Debug for Hand<R> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Hand [{self}]")
+        }
+    }
+
+    impl<R: RuleSet> FromStr for Hand<R> {
+        type Err = AocError;
+
+        fn from_str(s: &str) -> AocResult<Self> {
+            Self::parse(s)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        pub type NJHand = Hand<Standard>;
+        pub type JHand = Hand<Jokers>;
+
+
+        #[test]
+        fn test_hand_parse() {
+            assert_eq!(NJHand::parse("J23TA").map(|hand| hand.cards), Ok(vec![10, 1, 2, 9, 13]));
+            assert_eq!(JHand::parse("J23TA").map(|hand| hand.cards), Ok(vec![0, 2, 3, 10, 13]));
+            assert_eq!(NJHand::parse("J2XTA"), Err(AocError::bad_line(3, "invalid card 'X'")));
+            assert_eq!(NJHand::parse("J2"), Err(AocError::bad_line(1, "expected 5 cards in 'J2'")));
+        }
+
+        #[test]
+        fn test_classify() {
+            assert_eq!(NJHand::parse("12345").unwrap().classify(), HighCard);
+            assert_eq!(NJHand::parse("12343").unwrap().classify(), OnePair);
+            assert_eq!(NJHand::parse("1J2JJ").unwrap().classify(), ThreeOfAKind);
+            assert_eq!(NJHand::parse("AA3AA").unwrap().classify(), FourOfAKind);
+            assert_eq!(NJHand::parse("KKKKK").unwrap().classify(), FiveOfAKind);
+            assert_eq!(NJHand::parse("KKKQQ").unwrap().classify(), FullHouse);
+        }
+
+        #[test]
+        fn test_classify_jhand_without_joker() {
+            assert_eq!(JHand::parse("12345").unwrap().classify(), HighCard);
+            assert_eq!(JHand::parse("12343").unwrap().classify(), OnePair);
+            assert_eq!(JHand::parse("42343").unwrap().classify(), TwoPair);
+            assert_eq!(JHand::parse("1A2AA").unwrap().classify(), ThreeOfAKind);
+            assert_eq!(JHand::parse("AA3AA").unwrap().classify(), FourOfAKind);
+            assert_eq!(JHand::parse("KKKKK").unwrap().classify(), FiveOfAKind);
+            assert_eq!(JHand::parse("KKKQQ").unwrap().classify(), FullHouse);
+        }
+
+        #[test]
+        fn test_classify_jhand_with_joker() {
+            assert_eq!(JHand::parse("1234J").unwrap().classify(), OnePair);
+
+            assert_eq!(JHand::parse("J2343").unwrap().classify(), ThreeOfAKind);
+            assert_eq!(JHand::parse("12J4J").unwrap().classify(), ThreeOfAKind);
+
+            assert_eq!(JHand::parse("4J343").unwrap().classify(), FullHouse);
+            assert_eq!(JHand::parse("J23J3").unwrap().classify(), FourOfAKind);
+
+            assert_eq!(JHand::parse("JA2AA").unwrap().classify(), FourOfAKind);
+            assert_eq!(JHand::parse("1J2JJ").unwrap().classify(), FourOfAKind);
+
+            assert_eq!(JHand::parse("AAJAA").unwrap().classify(), FiveOfAKind);
+            assert_eq!(JHand::parse("JJ1JJ").unwrap().classify(), FiveOfAKind);
+
+            assert_eq!(JHand::parse("JJJJJ").unwrap().classify(), FiveOfAKind);
+            assert_eq!(JHand::parse("KKKJJ").unwrap().classify(), FiveOfAKind);
+            assert_eq!(JHand::parse("JJJTT").unwrap().classify(), FiveOfAKind);
+        }
+
+        /// Jokers and deuces are wild, ties go to the hand with the strongest cards.
+        struct DeucesWild;
+
+        impl RuleSet for DeucesWild {
+            const ORDER: &'static str = "J23456789TQKA";
+            const WILD: &'static str = "J2";
+
+            type Category = HandClassification;
+
+            fn category(signature: &[usize]) -> HandClassification {
+                HandClassification::of(signature)
+            }
+
+            fn tie_break(cards: &[u8]) -> Vec<u8> {
+                cards.iter().copied().sorted().rev().collect()
+            }
+        }
+
+        #[test]
+        fn test_custom_rule_set() {
+            type DHand = Hand<DeucesWild>;
+            assert_eq!(DHand::parse("2J345").unwrap().classify(), ThreeOfAKind);
+            assert_eq!(DHand::parse("22JJ3").unwrap().classify(), FiveOfAKind);
+            assert_eq!(DHand::parse("22JJ2").unwrap().classify(), FiveOfAKind);
+            assert_eq!(DHand::parse("3456K").unwrap().classify(), HighCard);
+            assert_eq!(DHand::parse("1").err(), Some(AocError::bad_line(1, "invalid card '1'")));
+            assert_eq!(DHand::parse("3A245").unwrap().cmp(&DHand::parse("K2345").unwrap()), Ordering::Greater);
+            assert_eq!(NJHand::parse("3A245").unwrap().cmp(&NJHand::parse("K2345").unwrap()), Ordering::Less);
+        }
+
+        /// Seven card hands of four colours, ranked by their signature.
+        struct Colours;
+
+        impl RuleSet for Colours {
+            const ORDER: &'static str = "RGBY";
+            const WILD: &'static str = "Y";
+            const HAND_SIZE: usize = 7;
+
+            type Category = Vec<usize>;
+
+            fn category(signature: &[usize]) -> Vec<usize> {
+                signature.to_vec()
+            }
+        }
+
+        #[test]
+        fn test_hand_size_and_deck() {
+            type CHand = Hand<Colours>;
+            assert_eq!(CHand::parse("RRGGBBR").unwrap().classify(), vec![3, 2, 2]);
+            assert_eq!(CHand::parse("RRRGGGB").unwrap().classify(), vec![3, 3, 1]);
+            assert_eq!(CHand::parse("RRRGGYB").unwrap().classify(), vec![4, 2, 1]);
+            assert_eq!(CHand::parse("YYYYYYY").unwrap().classify(), vec![7]);
+            assert!(CHand::parse("RRRGGGB").unwrap() > CHand::parse("BBBGGRR").unwrap());
+            assert!(CHand::parse("GGGRRRB").unwrap() > CHand::parse("RRRGGGB").unwrap());
+            assert_eq!(CHand::parse("RRGGBB").err(), Some(AocError::bad_line(1, "expected 7 cards in 'RRGGBB'")));
+            assert_eq!(CHand::parse("RRGGBBK").err(), Some(AocError::bad_line(7, "invalid card 'K'")));
+            assert_eq!(format!("{:?}", CHand::parse("RGBYRGB").unwrap()), "Hand [RGBYRGB]");
+        }
+
+        #[test]
+        fn test_strength() {
+            assert_eq!(JHand::parse("KTJJT").unwrap().strength(),
+                       &Strength { category: FourOfAKind, tie_break: vec![12, 10, 0, 0, 10] });
+            assert_eq!(Hand::<DeucesWild>::parse("3A245").unwrap().strength().tie_break, vec![12, 4, 3, 2, 1]);
+
+            let (left, right) = (Hand::<DeucesWild>::parse("3A245").unwrap(), Hand::<DeucesWild>::parse("A5432").unwrap());
+            assert_eq!(left.strength(), right.strength());
+            assert_eq!(left.cmp(&right), Ordering::Less);
+            assert_ne!(left, right);
+        }
+
+        #[test]
+        fn test_explain() {
+            assert_eq!(JHand::parse("KTJJT").unwrap().explain(),
+                       Explanation { category: FourOfAKind, substitutions: vec![(2, 'T'), (3, 'T')], groups: vec![('T', 4), ('K', 1)] });
+            assert_eq!(JHand::parse("JJJJJ").unwrap().explain(),
+                       Explanation { category: FiveOfAKind, substitutions: (0..5).map(|idx| (idx, 'A')).collect(), groups: vec![('A', 5)] });
+            assert_eq!(JHand::parse("1234J").unwrap().explain().substitutions, vec![(4, '4')]);
+            assert_eq!(NJHand::parse("KTJJT").unwrap().explain(),
+                       Explanation { category: TwoPair, substitutions: vec![], groups: vec![('J', 2), ('T', 2), ('K', 1)] });
+        }
+
+        #[test]
+        fn test_display_round_trip() {
+            let hand: JHand = "T55J5".parse().unwrap();
+            assert_eq!(hand.to_string(), "T55J5");
+            assert_eq!(format!("{hand:?}"), "Hand [T55J5]");
+            assert_eq!(hand.to_string().parse::<JHand>(), Ok(hand));
+            assert_eq!("T55".parse::<NJHand>().err(), Some(AocError::bad_line(1, "expected 5 cards in 'T55'")));
+        }
+
+        #[test]
+        fn test_hand_ordering() {
+            assert_eq!(NJHand::parse("33332").unwrap().cmp(&NJHand::parse("2AAAA").unwrap()), Ordering::Greater);
+            assert_eq!(NJHand::parse("2AAAA").unwrap().cmp(&NJHand::parse("33332").unwrap()), Ordering::Less);
+            assert_eq!(NJHand::parse("77888").unwrap().cmp(&NJHand::parse("77788").unwrap()), Ordering::Greater);
+            assert_eq!(NJHand::parse("77788").unwrap().cmp(&NJHand::parse("77888").unwrap()), Ordering::Less);
+            assert_eq!(NJHand::parse("A7A8A").unwrap().cmp(&NJHand::parse("A7A8A").unwrap()), Ordering::Equal);
+        }
+    }
+}
+
+mod exercise {
+    use itertools::Itertools;
+
+    use common::{AocError, AocResult, column_of, parse_lines, parse_token};
+
+    use crate::cards::{Hand, RuleSet};
+
+    fn parse_line<R: RuleSet>(line: &str) -> AocResult<(Hand<R>, usize)> {
+        let mut items = line.split_ascii_whitespace();
+        let cards = items.next().ok_or_else(|| AocError::missing(line, "hand"))?;
+        let hand = Hand::<R>::parse(cards).map_err(|error| error.offset_columns(column_of(line, cards) - 1))?;
+        let bid = parse_token(line, items.next().ok_or_else(|| AocError::missing(line, "bid"))?)?;
+        Ok((hand, bid))
+    }
+
+    pub fn parse_bids<R: RuleSet>(input: &[String]) -> AocResult<Vec<(Hand<R>, usize)>> {
+        parse_lines(input, parse_line::<R>)
+    }
+
+    /// The bids from the weakest hand to the strongest, with their 1-based rank.
+    fn ranked<R: RuleSet>(bids: &[(Hand<R>, usize)]) -> impl Iterator<Item=(usize, &Hand<R>, usize)> {
+        bids.iter()
+            .sorted_by_key(|(hand, _)| hand.strength())
+            .enumerate()
+            .map(|(idx, (hand, bid))| (idx + 1, hand, *bid))
+    }
+
+    pub fn compute<R: RuleSet>(bids: &[(Hand<R>, usize)]) -> usize {
+        ranked(bids)
+            .map(|(rank, _, bid)| rank * bid)
+            .sum()
+    }
+
+    /// One line per hand, weakest first, showing how it ranked and why.
+    pub fn ranked_table<R: RuleSet>(bids: &[(Hand<R>, usize)]) -> String {
+        let width = R::HAND_SIZE.max(4);
+        let mut table = format!("{:>4}  {:<width$}  {:<12}  {:<12}  {:>5}  {:>9}\n", "rank", "hand", "category", "substitution", "bid", "winnings");
+        for (rank, hand, bid) in ranked(bids) {
+            let explanation = hand.explain();
+            let substitution = match explanation.substitutions.as_slice() {
+                [] => "-".to_string(),
+                substitutions => substitutions.iter().map(|(idx, card)| format!("{}:{card}", idx + 1)).join(" "),
+            };
+            table += &format!("{rank:>4}  {:<width$}  {:<12}  {substitution:<12}  {bid:>5}  {:>9}\n",
+                              hand.to_string(), format!("{:?}", explanation.category), rank * bid);
+        }
+        table
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+
+        use crate::cards::{Jokers, Standard};
+
+        use super::*;
+
+        #[test]
+        fn test_compute() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            assert_eq!(compute(&parse_bids::<Standard>(&input).unwrap()), 6440);
+            assert_eq!(compute(&parse_bids::<Jokers>(&input).unwrap()), 5905);
+        }
+
+        #[test]
+        fn test_ranked_table() {
+            let input = load_aoc_input("test_data/e1.txt").unwrap();
+            assert_eq!(ranked_table(&parse_bids::<Jokers>(&input).unwrap()),
+                       "rank  hand   category      substitution    bid   winnings
+   1  32T3K  OnePair       -               765        765
+   2  KK677  TwoPair       -                28         56
+   3  T55J5  FourOfAKind   4:5             684       2052
+   4  QQQJA  FourOfAKind   4:Q             483       1932
+   5  KTJJT  FourOfAKind   3:T 4:T         220       1100
+");
+        }
+
+        #[test]
+        fn test_parse_line() {
+            assert!(parse_line::<Standard>("32T3K 765").is_ok());
+            assert_eq!(parse_line::<Standard>("32T3K"), Err(AocError::missing("32T3K", "bid")));
+            assert_eq!(parse_line::<Standard>("  32X3K 765"), Err(AocError::bad_line(5, "invalid card 'X'")));
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    /// Hands read both without and with jokers.
+    type Input = (Vec<(Hand<Standard>, usize)>, Vec<(Hand<Jokers>, usize)>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &[String]) -> AocResult<Self::Input> {
+        Ok((parse_bids(input)?, parse_bids(input)?))
+    }
+
+    fn part_one(&self, (bids, _): &Self::Input) -> AocResult<usize> {
+        Ok(compute(bids))
+    }
+
+    fn part_two(&self, (_, bids): &Self::Input) -> AocResult<usize> {
+        Ok(compute(bids))
+    }
+}
+
+/// The ranked tables of both parts, for settling disputes about the ordering.
+pub fn ranked_tables(input: &[String]) -> AocResult<String> {
+    let (bids, joker_bids) = Day7.parse(input)?;
+    Ok(format!("Part 1\n{}\nPart 2\n{}", ranked_table(&bids), ranked_table(&joker_bids)))
+}
@@ -0,0 +1,365 @@
+use common::{AocResult, Solution};
+
+use crate::map::Navigation;
+
+mod map {
+    use std::collections::{HashMap, HashSet};
+    use std::iter;
+    use std::ops::Rem;
+
+    use itertools::Itertools;
+
+    use common::{AocError, AocResult, blocks, column_of, parse_lines};
+
+    #[derive(PartialEq, Debug)]
+    pub struct MapNode {
+        pub id: String,
+        pub left: String,
+        pub right: String,
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub struct Navigation {
+        instructions: String,
+        map: HashMap<String, MapNode>,
+    }
+
+
+    #[derive(Debug)]
+    pub struct Journey<'a> {
+        nav: &'a Navigation,
+        pub node: &'a MapNode,
+        position: usize,
+    }
+
+    #[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Position {
+        pub position: usize,
+        pub node_id: String
+    }
+    impl<'a> Journey<'a> {
+        pub fn pos(&self) -> Position {
+            Position{
+                position: self.position.rem(&self.nav.instructions.len()),
+                node_id: self.node.id.clone(),
+            }
+        }
+
+        /// The position before every step, starting with the current one.
+        pub fn positions(mut self) -> impl Iterator<Item=Position> + 'a {
+            iter::once(self.pos()).chain(iter::from_fn(move || {
+                self.next();
+                Some(self.pos())
+            }))
+        }
+    }
+
+    impl<'a> Iterator for Journey<'a> {
+        type Item = &'a MapNode;
+        fn next(&mut self) -> Option<Self::Item> {
+            let instr = &self.nav.instructions;
+            let instr_char = instr.chars().nth(self.position.rem(instr.len())).unwrap();
+            let node = match instr_char {
+                'R' => &self.node.right,
+                'L' => &self.node.left,
+                _ => panic!("Invalid instruction {instr_char}")
+            };
+            self.node = self.nav.map.get(node).unwrap();
+            self.position += 1;
+
+            Some(self.node)
+        }
+    }
+
+    impl MapNode {
+        pub fn parse(input: &str) -> AocResult<Self> {
+            let (id, left, right) = input.split(['=', ' ', '(', ',', ')'])
+                .filter(|token| !token.is_empty())
+                .map(|token| token.to_string())
+                .collect_tuple()
+                .ok_or_else(|| AocError::bad_line(1, "expected '<id> = (<left>, <right>)'"))?;
+
+            Ok(Self { id, left, right })
+        }
+    }
+
+    fn parse_instructions(block: &[String]) -> AocResult<String> {
+        let [instructions] = block else {
+            return Err(AocError::bad_line(1, "expected a blank line after the instructions").offset_lines(1));
+        };
+        if let Some((idx, c)) = instructions.chars().enumerate().find(|(_, c)| *c != 'L' && *c != 'R') {
+            return Err(AocError::bad_line(idx + 1, format!("invalid instruction '{c}'")));
+        }
+        Ok(instructions.clone())
+    }
+
+    impl Navigation {
+        /// Reads the instructions line and, after a blank line, the block of nodes.
+        pub fn load_map(input: &[String]) -> AocResult<Self> {
+            let blocks = blocks(input);
+            let (header, nodes_offset, nodes) = match &blocks[..] {
+                [] => return Err(AocError::UnexpectedEnd("instructions".to_string())),
+                [header] => (header, 0, &[][..]),
+                [header, nodes] => (header, nodes.offset, nodes.lines),
+                [_, _, extra, ..] => return Err(AocError::bad_line(1, "expected end of input").offset_lines(extra.offset)),
+            };
+            let instructions = header.parse(parse_instructions)?;
+
+            let nodes: Vec<MapNode> = parse_lines(nodes, MapNode::parse)
+                .map_err(|error| error.offset_lines(nodes_offset))?;
+
+            let ids: HashSet<&String> = nodes.iter().map(|node| &node.id).collect();
+            for (idx, node) in nodes.iter().enumerate() {
+                for target in [&node.left, &node.right] {
+                    if !ids.contains(target) {
+                        let line = &input[nodes_offset + idx];
+                        let column = line.rfind(target.as_str()).map_or(1, |offset| column_of(line, &line[offset..]));
+                        return Err(AocError::bad_line(column, format!("unknown node '{target}'")).offset_lines(nodes_offset + idx));
+                    }
+                }
+            }
+
+            let map = nodes.into_iter().map(|node| (node.id.clone(), node)).collect();
+            Ok(Self { instructions, map })
+        }
+
+        pub fn journey(&self, start_node: &str) -> AocResult<Journey<'_>> {
+            let node = self.map.get(start_node)
+                .ok_or_else(|| AocError::NoSolution(format!("missing start node '{start_node}'")))?;
+            Ok(Journey { nav: self, node, position: 0 })
+        }
+
+        pub fn ghost_start(&self) -> Vec<String> {
+            self.map.keys().filter(|key| key.ends_with('A')).cloned().collect()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::load_aoc_input;
+
+        use super::*;
+
+        #[test]
+        fn test_map_node_parse() {
+            assert_eq!(MapNode::parse("AAA = (BBB, CCC)"), Ok(MapNode { id: "AAA".to_string(), left: "BBB".to_string(), right: "CCC".to_string() }));
+            assert!(MapNode::parse("AAA = (BBB)").is_err());
+        }
+
+        #[test]
+        fn test_navigation_parse() {
+            let input = load_aoc_input("test_data/e2.txt").unwrap();
+            assert_eq!(Navigation::load_map(&input),
+                       Ok(Navigation {
+                           instructions: "LLR".to_string(),
+                           map: HashMap::from([
+                               ("AAA".to_string(), MapNode::parse("AAA = (BBB, BBB)").unwrap()),
+                               ("BBB".to_string(), MapNode::parse("BBB = (AAA, ZZZ)").unwrap()),
+                               ("ZZZ".to_string(), MapNode::parse("ZZZ = (ZZZ, ZZZ)").unwrap()),
+                           ]),
+                       }))
+        }
+
+        #[test]
+        fn test_navigation_parse_errors() {
+            let input = |lines: &[&str]| lines.iter().map(|line| line.to_string()).collect::<Vec<String>>();
+
+            assert_eq!(Navigation::load_map(&input(&["LXR"])), Err(AocError::bad_line(2, "invalid instruction 'X'")));
+            assert_eq!(Navigation::load_map(&input(&["LR", "", "AAA = (BBB, AAA)", "BBB"])),
+                       Err(AocError::BadLine { line: 4, column: 1, message: "expected '<id> = (<left>, <right>)'".to_string() }));
+            assert_eq!(Navigation::load_map(&input(&["LR", "", "AAA = (AAA, AAA)", "BBB = (AAA, CCC)"])),
+                       Err(AocError::BadLine { line: 4, column: 13, message: "unknown node 'CCC'".to_string() }));
+            assert_eq!(Navigation::load_map(&input(&["LR", "AAA = (AAA, AAA)"])),
+                       Err(AocError::BadLine { line: 2, column: 1, message: "expected a blank line after the instructions".to_string() }));
+            assert_eq!(Navigation::load_map(&input(&["LR", "", "AAA = (AAA, AAA)", "", "BBB = (AAA, AAA)"])),
+                       Err(AocError::BadLine { line: 5, column: 1, message: "expected end of input".to_string() }));
+        }
+
+        #[test]
+        fn test_navigation_journey() {
+            let input = load_aoc_input("test_data/e2.txt").unwrap();
+            let navigation = Navigation::load_map(&input).unwrap();
+            let mut journey = navigation.journey("AAA").unwrap();
+            let a_node = MapNode::parse("AAA = (BBB, BBB)").unwrap();
+            let b_node = MapNode::parse("BBB = (AAA, ZZZ)").unwrap();
+            let z_node = MapNode::parse("ZZZ = (ZZZ, ZZZ)").unwrap();
+            assert_eq!(journey.next(), Some(&b_node));
+            assert_eq!(journey.next(), Some(&a_node));
+            assert_eq!(journey.next(), Some(&b_node));
+            assert_eq!(journey.next(), Some(&a_node));
+            assert_eq!(journey.next(), Some(&b_node));
+            assert_eq!(journey.next(), Some(&z_node));
+            assert_eq!(journey.next(), Some(&z_node));
+            assert_eq!(journey.next(), Some(&z_node));
+            assert_eq!(journey.next(), Some(&z_node));
+        }
+
+        #[test]
+        fn test_navigation_journey_missing_start() {
+            let navigation = Navigation::load_map(&load_aoc_input("test_data/e3.txt").unwrap()).unwrap();
+            assert_eq!(navigation.journey("AAA").err(), Some(AocError::NoSolution("missing start node 'AAA'".to_string())));
+        }
+    }
+}
+
+mod example1 {
+    use common::{AocError, AocResult, find_cycle};
+
+    use crate::map::Navigation;
+
+    /// Steps from AAA to ZZZ, found by following the journey until its state repeats.
+    pub fn compute(navigation: &Navigation) -> AocResult<usize> {
+        find_cycle(navigation.journey("AAA")?.positions(), |pos| pos.node_id == "ZZZ")
+            .and_then(|cycle| cycle.goal_indices().next())
+            .ok_or_else(|| AocError::NoSolution("ZZZ cannot be reached from AAA".to_string()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::{aoc_input_from_str, load_aoc_input};
+        use super::*;
+
+        #[test]
+        fn test_compute() {
+            for (example, expected) in [("test_data/e1.txt", 2), ("test_data/e2.txt", 6)] {
+                let navigation = Navigation::load_map(&load_aoc_input(example).unwrap()).unwrap();
+                assert_eq!(compute(&navigation), Ok(expected));
+            }
+        }
+
+        #[test]
+        fn test_compute_unreachable() {
+            let no_solution = Err(AocError::NoSolution("ZZZ cannot be reached from AAA".to_string()));
+            for input in ["LR\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)", "LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)"] {
+                let navigation = Navigation::load_map(&aoc_input_from_str(input)).unwrap();
+                assert_eq!(compute(&navigation), no_solution);
+            }
+        }
+    }
+}
+
+mod example2 {
+    use itertools::{Itertools, process_results};
+
+    use common::{AocError, AocResult, Cycle, crt, find_cycle};
+
+    use crate::map::Navigation;
+
+    fn ghost_cycles(navigation: &Navigation) -> AocResult<Vec<Cycle>> {
+        let mut cycles = Vec::new();
+        for start_point in navigation.ghost_start() {
+            cycles.extend(find_cycle(navigation.journey(&start_point)?.positions(), |pos| pos.node_id.ends_with('Z')));
+        }
+        Ok(cycles)
+    }
+
+    /// First step at which every cycle is on a goal.
+    fn first_common_goal(cycles: &[Cycle]) -> AocResult<Option<usize>> {
+        let Some(settled) = cycles.iter().map(|cycle| cycle.start).max() else {
+            return Ok(None);
+        };
+
+        // Before `settled` a common goal has to be a lead-in goal of the ghost which settles last.
+        let early = cycles.iter()
+            .flat_map(|cycle| cycle.lead_in_goals.iter().copied())
+            .filter(|step| cycles.iter().all(|cycle| cycle.is_goal(*step)))
+            .min();
+        if early.is_some() {
+            return Ok(early);
+        }
+
+        // Afterwards every ghost is on a goal when the step matches one of its goal offsets.
+        let solutions = cycles.iter()
+            .map(|cycle| cycle.goal_offsets.iter()
+                .map(|offset| (((cycle.start + offset) % cycle.length) as u64, cycle.length as u64))
+                .collect::<Vec<_>>())
+            .multi_cartesian_product()
+            .map(|congruences| crt(&congruences));
+        process_results(solutions, |solutions| solutions.flatten()
+            .map(|(step, period)| {
+                let (step, period) = (step as usize, period as usize);
+                step + settled.saturating_sub(step).div_ceil(period) * period
+            })
+            .min())
+    }
+
+    pub fn compute(navigation: &Navigation) -> AocResult<usize> {
+        let cycles = ghost_cycles(navigation)?;
+        if cycles.is_empty() {
+            return Err(AocError::NoSolution("no start node ending in 'A'".to_string()));
+        }
+        first_common_goal(&cycles)?
+            .ok_or_else(|| AocError::NoSolution("the ghosts are never all on nodes ending in 'Z' at once".to_string()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::{aoc_input_from_str, load_aoc_input};
+        use super::*;
+
+        #[test]
+        fn test_ghost_cycles() {
+            let navigation = Navigation::load_map(&load_aoc_input("test_data/e3.txt").unwrap()).unwrap();
+            let mut cycles = ghost_cycles(&navigation).unwrap();
+            cycles.sort_by_key(|cycle| cycle.length);
+            assert_eq!(cycles, vec![
+                Cycle { start: 1, length: 2, lead_in_goals: vec![], goal_offsets: vec![1] },
+                Cycle { start: 1, length: 6, lead_in_goals: vec![], goal_offsets: vec![2, 5] },
+            ]);
+        }
+
+        #[test]
+        fn test_first_common_goal() {
+            let cycle = |start, length, lead_in_goals: &[usize], goal_offsets: &[usize]| Cycle {
+                start, length, lead_in_goals: lead_in_goals.to_vec(), goal_offsets: goal_offsets.to_vec(),
+            };
+
+            assert_eq!(first_common_goal(&[cycle(0, 4, &[], &[3]), cycle(0, 6, &[], &[1])]), Ok(Some(7)));
+            assert_eq!(first_common_goal(&[cycle(0, 4, &[], &[3]), cycle(5, 6, &[3], &[0])]), Ok(Some(3)));
+            assert_eq!(first_common_goal(&[cycle(0, 4, &[], &[1]), cycle(10, 6, &[], &[1])]), Ok(Some(17)));
+            assert_eq!(first_common_goal(&[cycle(0, 4, &[], &[1]), cycle(0, 6, &[], &[2])]), Ok(None));
+            assert_eq!(first_common_goal(&[]), Ok(None));
+            assert_eq!(first_common_goal(&[cycle(0, (1 << 40) + 15, &[], &[1]), cycle(0, 1 << 40, &[], &[0])]),
+                       Err(AocError::Overflow("the lcm of the moduli does not fit in a u64".to_string())));
+        }
+
+        #[test]
+        fn test_compute() {
+            let navigation = Navigation::load_map(&load_aoc_input("test_data/e3.txt").unwrap()).unwrap();
+            assert_eq!(compute(&navigation), Ok(6));
+        }
+
+        #[test]
+        fn test_compute_without_answer() {
+            // 11A is on 11Z after odd steps, 22A on 22Z after even ones.
+            let input = "LR\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+            let navigation = Navigation::load_map(&aoc_input_from_str(input)).unwrap();
+            assert_eq!(compute(&navigation), Err(AocError::NoSolution("the ghosts are never all on nodes ending in 'Z' at once".to_string())));
+
+            let navigation = Navigation::load_map(&aoc_input_from_str("LR\n\nBBB = (BBB, BBB)")).unwrap();
+            assert_eq!(compute(&navigation), Err(AocError::NoSolution("no start node ending in 'A'".to_string())));
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Navigation;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &[String]) -> AocResult<Navigation> {
+        Navigation::load_map(input)
+    }
+
+    fn part_one(&self, input: &Navigation) -> AocResult<usize> {
+        example1::compute(input)
+    }
+
+    fn part_two(&self, input: &Navigation) -> AocResult<usize> {
+        example2::compute(input)
+    }
+}
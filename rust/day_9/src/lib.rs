@@ -0,0 +1,125 @@
+use common::{AocResult, Solution};
+
+use crate::exercise1::{calculate, parse_histories};
+
+mod exercise1 {
+    use itertools::Itertools;
+
+    use common::{AocError, AocResult, parse_lines, parse_token};
+
+    fn transform(history: Vec<i32>) -> Vec<i32> {
+        history.iter().tuple_windows::<(_,_)>().map(|item| item.1 - item.0).collect()
+    }
+
+    /// A constant row extrapolates to itself, so the differences stop there.
+    fn extrapolate_front(mut history: Vec<i32>) -> i32 {
+        let mut acc = 0;
+        while let Some(last) = history.last() {
+            acc += last;
+            if history.iter().all_equal() {
+                break
+            }
+            history = transform(history);
+        }
+        acc
+    }
+
+    fn extrapolate_back(mut history: Vec<i32>) -> i32 {
+        let mut acc = 0;
+        let mut sign: i32 = 1;
+        while let Some(first) = history.first() {
+            acc += sign*first;
+            if history.iter().all_equal() {
+                break
+            }
+            history = transform(history);
+            sign *= -1;
+        }
+        acc
+    }
+
+    fn parse_history(line: &str) -> AocResult<Vec<i32>> {
+        let history: Vec<i32> = line.split_whitespace().map(|num| parse_token(line, num)).collect::<AocResult<_>>()?;
+        if history.len() < 2 {
+            return Err(AocError::missing(line, "at least two values"));
+        }
+        Ok(history)
+    }
+
+    pub fn parse_histories(lines: &[String]) -> AocResult<Vec<Vec<i32>>> {
+        parse_lines(lines, parse_history)
+    }
+
+    pub fn calculate(histories: &[Vec<i32>], backward: bool) -> i32 {
+        histories.iter().cloned().map(|history| if backward {
+            extrapolate_back(history)
+        }  else {
+            extrapolate_front(history)
+        }).sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use common::aoc_input_from_str;
+        use super::*;
+
+        const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        #[test]
+        fn test_calculate() {
+            let histories = parse_histories(&aoc_input_from_str(EXAMPLE)).unwrap();
+            assert_eq!(calculate(&histories, false), 114);
+            assert_eq!(calculate(&histories, true), 2);
+        }
+
+        #[test]
+        fn test_parse_history() {
+            assert_eq!(parse_history("0 -3 6"), Ok(vec![0, -3, 6]));
+            assert!(parse_history("0 3a 6").is_err());
+            assert_eq!(parse_history(""), Err(AocError::missing("", "at least two values")));
+            assert_eq!(parse_history("7"), Err(AocError::missing("7", "at least two values")));
+        }
+
+        #[test]
+        fn test_transform() {
+            assert_eq!(transform(vec![10, 13, 16, 21, 30, 45, 68]), vec![3, 3, 5, 9, 15, 23]);
+        }
+
+        #[test]
+        fn test_extrapolate_front() {
+            assert_eq!(extrapolate_front(vec![10, 13, 16, 21, 30, 45]), 68);
+            assert_eq!(extrapolate_front(vec![1, 2, 4]), 7);
+        }
+
+        #[test]
+        fn test_extrapolate_back() {
+            assert_eq!(extrapolate_back(vec![10, 13, 16, 21, 30, 45]), 5);
+            assert_eq!(extrapolate_back(vec![1,3,6,10,15,21]), 0);
+            assert_eq!(extrapolate_back(vec![1, 2, 4]), 1);
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, input: &[String]) -> AocResult<Vec<Vec<i32>>> {
+        parse_histories(input)
+    }
+
+    fn part_one(&self, input: &Vec<Vec<i32>>) -> AocResult<i32> {
+        Ok(calculate(input, false))
+    }
+
+    fn part_two(&self, input: &Vec<Vec<i32>>) -> AocResult<i32> {
+        Ok(calculate(input, true))
+    }
+}